@@ -0,0 +1,167 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+};
+
+use common::ParseError;
+
+/// Where `aoc verify` looks for known-good answers unless told otherwise.
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+const HEADER: &str = "\
+# Known-good answers for our puzzle inputs, checked by `aoc verify`.
+# One answer per line: day, part, answer. Newlines in answers are written as \\n.
+";
+
+/// Known-good answers, per day and part.
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut answers = Answers::default();
+
+        for (index, line) in input.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut tokens = line.splitn(3, ' ');
+            let day = tokens.next().unwrap_or(line);
+            let part = tokens.next().unwrap_or(&line[line.len()..]);
+            let answer = tokens.next().unwrap_or(&line[line.len()..]);
+
+            let day = day
+                .parse::<u8>()
+                .map_err(|_| ParseError::new(index, line, day, "expected a day"))?;
+            let part = match part {
+                "1" => 1,
+                "2" => 2,
+                _ => return Err(ParseError::new(index, line, part, "expected part 1 or 2")),
+            };
+
+            if answer.is_empty() {
+                return Err(ParseError::line(index, line, "expected an answer"));
+            }
+
+            if answers
+                .answers
+                .insert((day, part), unescape(answer))
+                .is_some()
+            {
+                return Err(ParseError::line(
+                    index,
+                    line,
+                    format!("day {day} part {part} has already been answered"),
+                ));
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: u8, part: u8, answer: String) {
+        self.answers.insert((day, part), answer);
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Status {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Status::Pass,
+            Some(expected) => Status::Fail {
+                expected: expected.to_string(),
+            },
+            None => Status::Missing,
+        }
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{HEADER}")?;
+
+        for ((day, part), answer) in &self.answers {
+            writeln!(f, "{day:02} {part} {}", escape(answer))?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> String {
+    let mut unescaped = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                unescaped.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                unescaped.push('\\');
+                chars.next();
+            }
+            _ => unescaped.push(c),
+        }
+    }
+
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::{Answers, Status};
+
+    #[test]
+    fn answers_survive_a_round_trip() {
+        let mut answers = Answers::default();
+
+        answers.insert(1, 1, "24000".to_string());
+        answers.insert(10, 2, "#..#\n.##.\\".to_string());
+
+        let written = answers.to_string();
+
+        assert!(written.contains("\n01 1 24000\n10 2 #..#\\n.##.\\\\\n"));
+        assert_eq!(Answers::parse(&written).unwrap(), answers);
+    }
+
+    #[test]
+    fn answers_are_checked_per_part() {
+        let answers = Answers::parse("# comment\n\n07 1 95437\n").unwrap();
+
+        assert_eq!(answers.check(7, 1, "95437"), Status::Pass);
+        assert_eq!(
+            answers.check(7, 1, "95438"),
+            Status::Fail {
+                expected: "95437".to_string()
+            }
+        );
+        assert_eq!(answers.check(7, 2, "24933642"), Status::Missing);
+    }
+
+    #[test]
+    fn malformed_lines_are_rejected() {
+        let error = Answers::parse("01 1 24000\n01 3 45000").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 4));
+        assert!(Answers::parse("01 1 1\n01 1 2").is_err());
+        assert!(Answers::parse("01 2").is_err());
+    }
+}
@@ -0,0 +1,245 @@
+use std::{
+    fmt::Write,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use common::{report::Format, ParseError, Puzzle};
+
+/// Summary of repeated measurements of the same step.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Timings {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Timings {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "Cannot summarise zero samples");
+
+        samples.sort();
+
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        Self {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Timings of every step of a single day.
+#[derive(Debug)]
+pub struct DayBench {
+    pub day: u8,
+    pub parse: Timings,
+    pub part1: Timings,
+    pub part2: Timings,
+}
+
+impl DayBench {
+    pub fn steps(&self) -> [(&'static str, Timings); 3] {
+        [
+            ("parse", self.parse),
+            ("part1", self.part1),
+            ("part2", self.part2),
+        ]
+    }
+}
+
+/// Parses `input` and solves both parts `runs` times each, timing every step
+/// on its own so that slow parsing does not hide in the part timings.
+pub fn bench(day: &dyn Puzzle, input: &str, runs: usize) -> Result<DayBench, ParseError> {
+    let mut parse = Vec::with_capacity(runs);
+    // Also serves as a warm-up run, and stops early on unparseable input
+    let mut parsed = day.parse_input(input)?;
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let next = black_box(day.parse_input(black_box(input))?);
+        parse.push(start.elapsed());
+
+        // Dropping the previous input is not part of parsing
+        parsed = next;
+    }
+
+    // Records time only the solver, not turning its answer into a string
+    let time_part = |part| {
+        (0..runs)
+            .map(|_| black_box(day.record(part, &*parsed)).elapsed)
+            .collect::<Vec<_>>()
+    };
+
+    let part1 = time_part(1);
+    let part2 = time_part(2);
+
+    Ok(DayBench {
+        day: day.day(),
+        parse: Timings::from_samples(parse),
+        part1: Timings::from_samples(part1),
+        part2: Timings::from_samples(part2),
+    })
+}
+
+pub fn render(results: &[DayBench], runs: usize, format: Format) -> String {
+    match format {
+        Format::Text => table(results),
+        Format::Json => to_json(results, runs),
+        Format::Csv => to_csv(results),
+    }
+}
+
+fn table(results: &[DayBench]) -> String {
+    let mut table = format!(
+        "Day  Step   {:>12}  {:>12}  {:>12}\n",
+        "Min", "Median", "Max"
+    );
+    writeln!(table, "---  -----  {0}  {0}  {0}", "-".repeat(12)).unwrap();
+
+    for result in results {
+        for (step, timings) in result.steps() {
+            writeln!(
+                table,
+                "{:>3}  {step}  {:>12}  {:>12}  {:>12}",
+                result.day,
+                format!("{:.2?}", timings.min),
+                format!("{:.2?}", timings.median),
+                format!("{:.2?}", timings.max),
+            )
+            .unwrap();
+        }
+    }
+
+    table
+}
+
+/// Renders the results as JSON, with every timing in nanoseconds so that runs
+/// from different commits can be compared without any unit conversions.
+pub fn to_json(results: &[DayBench], runs: usize) -> String {
+    let mut json = format!("{{\n  \"runs\": {runs},\n  \"days\": [");
+
+    for (index, result) in results.iter().enumerate() {
+        let separator = if index == 0 { "" } else { "," };
+        write!(json, "{separator}\n    {{\n      \"day\": {}", result.day).unwrap();
+
+        for (step, timings) in result.steps() {
+            write!(
+                json,
+                ",\n      \"{step}\": {{ \"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {} }}",
+                timings.min.as_nanos(),
+                timings.median.as_nanos(),
+                timings.max.as_nanos()
+            )
+            .unwrap();
+        }
+
+        json.push_str("\n    }");
+    }
+
+    if !results.is_empty() {
+        json.push_str("\n  ");
+    }
+
+    json.push_str("]\n}\n");
+
+    json
+}
+
+/// One row per step of every day, with timings in nanoseconds like
+/// [`to_json`].
+pub fn to_csv(results: &[DayBench]) -> String {
+    let mut csv = "day,step,min_ns,median_ns,max_ns\n".to_string();
+
+    for result in results {
+        for (step, timings) in result.steps() {
+            writeln!(
+                csv,
+                "{},{step},{},{},{}",
+                result.day,
+                timings.min.as_nanos(),
+                timings.median.as_nanos(),
+                timings.max.as_nanos()
+            )
+            .unwrap();
+        }
+    }
+
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::bench::{bench, to_csv, to_json, DayBench, Timings};
+
+    fn ms(millis: &[u64]) -> Vec<Duration> {
+        millis.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[test]
+    fn median_of_odd_and_even_sample_counts() {
+        let odd = Timings::from_samples(ms(&[5, 1, 3]));
+        let even = Timings::from_samples(ms(&[4, 1, 2, 8]));
+
+        assert_eq!(odd.min, Duration::from_millis(1));
+        assert_eq!(odd.median, Duration::from_millis(3));
+        assert_eq!(odd.max, Duration::from_millis(5));
+        assert_eq!(even.median, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn every_step_is_timed() {
+        let result = bench(&day06::Day06, "mjqjpqmgbljsphdztnvjfqwrcgsmlb", 3).unwrap();
+
+        assert_eq!(result.day, 6);
+        assert!(result.part1.min <= result.part1.median);
+        assert!(result.part2.median <= result.part2.max);
+    }
+
+    #[test]
+    fn json_has_nanosecond_timings_per_step() {
+        let timings = Timings::from_samples(ms(&[1]));
+        let result = DayBench {
+            day: 7,
+            parse: timings,
+            part1: timings,
+            part2: timings,
+        };
+
+        let json = to_json(&[result], 1);
+
+        assert!(json.starts_with("{\n  \"runs\": 1,"));
+        assert!(json.contains("\"day\": 7"));
+        assert!(json.contains(
+            "\"part2\": { \"min_ns\": 1000000, \"median_ns\": 1000000, \"max_ns\": 1000000 }"
+        ));
+        assert_eq!(to_json(&[], 5), "{\n  \"runs\": 5,\n  \"days\": []\n}\n");
+    }
+
+    #[test]
+    fn csv_has_one_row_per_step() {
+        let result = DayBench {
+            day: 7,
+            parse: Timings::from_samples(ms(&[1])),
+            part1: Timings::from_samples(ms(&[1, 2, 3])),
+            part2: Timings::from_samples(ms(&[2])),
+        };
+
+        assert_eq!(
+            to_csv(&[result]),
+            "day,step,min_ns,median_ns,max_ns\n\
+             7,parse,1000000,1000000,1000000\n\
+             7,part1,1000000,2000000,3000000\n\
+             7,part2,2000000,2000000,2000000\n"
+        );
+        assert_eq!(to_csv(&[]), "day,step,min_ns,median_ns,max_ns\n");
+    }
+}
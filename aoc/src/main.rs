@@ -0,0 +1,267 @@
+use std::{env, fs, path::Path, process::exit, thread};
+
+use answers::{Answers, ANSWERS_FILE};
+use common::{input, report::Format, Puzzle};
+
+mod answers;
+mod bench;
+mod parallel;
+mod registry;
+mod scaffold;
+mod verify;
+
+use registry::DAYS;
+
+const USAGE: &str = "Usage:
+    aoc run [DAY...] [--part <1|2>] [--input <PATH|->] [--format <json|csv|text>] [--jobs <N>]
+                                       Solve the given days, or every day if none are given,
+                                       on N threads (one per CPU by default)
+    aoc bench [DAY...] [--runs <N>] [--format <json|csv|text>] [--json <PATH>]
+                                       Time parsing and both parts of the given days, or every day
+    aoc verify [DAY...] [--answers <PATH>] [--record] [--format <json|csv|text>]
+                                       Check the given days, or every day, against known answers;
+                                       --record adds answers that are not known yet
+    aoc new <DAY>                      Create dayNN/ from the dayxx template
+    aoc list                           List every registered day";
+
+fn parse_day(arg: &str) -> Result<&'static dyn Puzzle, String> {
+    let number = arg
+        .trim_start_matches("day")
+        .parse::<u8>()
+        .map_err(|_| format!("\"{arg}\" is not a day"))?;
+
+    registry::find(number).ok_or_else(|| format!("Day {number} is not registered"))
+}
+
+fn parse_part(arg: Option<String>) -> Result<u8, String> {
+    match arg.as_deref() {
+        Some("1") => Ok(1),
+        Some("2") => Ok(2),
+        Some(other) => Err(format!("\"{other}\" is not a part, expected 1 or 2")),
+        None => Err("--part needs a value".to_string()),
+    }
+}
+
+fn parse_format(arg: &str, value: Option<String>) -> Result<Format, String> {
+    match value {
+        Some(name) => name.parse(),
+        None => Err(format!("{arg} needs one of json, csv or text")),
+    }
+}
+
+fn run(args: impl Iterator<Item = String>) -> Result<bool, String> {
+    let mut days: Vec<&dyn Puzzle> = Vec::new();
+    let mut parts = vec![1, 2];
+    let mut source = None;
+    let mut format = Format::Text;
+    let mut jobs = thread::available_parallelism().map_or(1, |jobs| jobs.get());
+    let mut args = args;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => parts = vec![parse_part(args.next())?],
+            "--input" | "-i" => match args.next() {
+                Some(path) => source = Some(path),
+                None => return Err(format!("{arg} needs a path, or - for stdin")),
+            },
+            "--format" | "-f" => format = parse_format(&arg, args.next())?,
+            "--jobs" | "-j" => {
+                jobs = match args.next().map(|jobs| jobs.parse::<usize>()) {
+                    Some(Ok(jobs)) if jobs > 0 => jobs,
+                    _ => return Err(format!("{arg} needs a positive number")),
+                }
+            }
+            _ => days.push(parse_day(&arg)?),
+        }
+    }
+
+    if days.is_empty() {
+        days = DAYS.to_vec();
+    }
+
+    if source.is_some() && days.len() != 1 {
+        return Err("--input can only be used when solving a single day".to_string());
+    }
+
+    let load = |day| input::load(day, source.as_deref()).map_err(|error| error.to_string());
+
+    let mut records = Vec::new();
+    let mut all_ok = true;
+
+    for result in parallel::run(&days, &parts, jobs, &load) {
+        for error in &result.errors {
+            eprintln!("{error}");
+            all_ok = false;
+        }
+
+        records.extend(result.records);
+    }
+
+    print!("{}", format.render(&records));
+
+    Ok(all_ok)
+}
+
+fn run_bench(args: impl Iterator<Item = String>) -> Result<bool, String> {
+    let mut days: Vec<&dyn Puzzle> = Vec::new();
+    let mut runs = 10;
+    let mut format = Format::Text;
+    let mut json = None;
+    let mut args = args;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--runs" | "-n" => {
+                runs = match args.next().map(|runs| runs.parse::<usize>()) {
+                    Some(Ok(runs)) if runs > 0 => runs,
+                    _ => return Err(format!("{arg} needs a positive number")),
+                }
+            }
+            "--format" | "-f" => format = parse_format(&arg, args.next())?,
+            "--json" => match args.next() {
+                Some(path) => json = Some(path),
+                None => return Err(format!("{arg} needs a path")),
+            },
+            _ => days.push(parse_day(&arg)?),
+        }
+    }
+
+    if days.is_empty() {
+        days = DAYS.to_vec();
+    }
+
+    let mut results = Vec::new();
+    let mut all_ok = true;
+
+    for day in days {
+        let input = match input::load(day.day(), None) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("{error}");
+                all_ok = false;
+                continue;
+            }
+        };
+
+        match bench::bench(day, &input, runs) {
+            Ok(result) => results.push(result),
+            Err(error) => {
+                eprintln!("Could not parse input for day {}: {error}", day.day());
+                all_ok = false;
+            }
+        }
+    }
+
+    print!("{}", bench::render(&results, runs, format));
+
+    if let Some(path) = json {
+        if let Err(error) = fs::write(&path, bench::to_json(&results, runs)) {
+            eprintln!("Could not write {path}: {error}");
+            all_ok = false;
+        }
+    }
+
+    Ok(all_ok)
+}
+
+fn run_verify(args: impl Iterator<Item = String>) -> Result<bool, String> {
+    let mut days: Vec<&dyn Puzzle> = Vec::new();
+    let mut path = ANSWERS_FILE.to_string();
+    let mut record = false;
+    let mut format = Format::Text;
+    let mut args = args;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answers" => match args.next() {
+                Some(answers) => path = answers,
+                None => return Err(format!("{arg} needs a path")),
+            },
+            "--record" => record = true,
+            "--format" | "-f" => format = parse_format(&arg, args.next())?,
+            _ => days.push(parse_day(&arg)?),
+        }
+    }
+
+    if days.is_empty() {
+        days = DAYS.to_vec();
+    }
+
+    let mut answers = match fs::read_to_string(&path) {
+        Ok(text) => Answers::parse(&text).map_err(|error| format!("{path}: {error}"))?,
+        Err(error) if record && error.kind() == std::io::ErrorKind::NotFound => Answers::default(),
+        Err(error) => return Err(format!("Could not read {path}: {error}")),
+    };
+
+    let mut checks = Vec::new();
+
+    for day in days {
+        let input = input::load(day.day(), None).ok();
+        checks.extend(verify::verify(day, input.as_deref(), &mut answers, record));
+    }
+
+    print!("{}", verify::render(&checks, format));
+
+    let failed = checks.iter().any(|check| check.outcome.is_failure());
+    let recorded = checks
+        .iter()
+        .filter(|check| matches!(check.outcome, verify::Outcome::Recorded { .. }))
+        .count();
+
+    if recorded > 0 {
+        fs::write(&path, answers.to_string())
+            .map_err(|error| format!("Could not write {path}: {error}"))?;
+    }
+
+    Ok(!failed)
+}
+
+fn run_new(args: impl Iterator<Item = String>) -> Result<bool, String> {
+    let args = args.collect::<Vec<_>>();
+
+    let day = match args.as_slice() {
+        [day] => match day.trim_start_matches("day").parse::<u8>() {
+            Ok(day @ 1..=25) => day,
+            _ => return Err(format!("\"{day}\" is not a day from 1 to 25")),
+        },
+        _ => return Err("new needs exactly one day".to_string()),
+    };
+
+    for path in scaffold::scaffold(Path::new("."), day)? {
+        println!("Created {}", path.display());
+    }
+
+    println!("Add day{day:02} to aoc/Cargo.toml and aoc/src/registry.rs to solve it with aoc run");
+
+    Ok(true)
+}
+
+fn main() {
+    let mut args = env::args().skip(1);
+
+    let result = match args.next().as_deref() {
+        Some("run") => run(args),
+        Some("bench") => run_bench(args),
+        Some("verify") => run_verify(args),
+        Some("new") => run_new(args),
+        Some("list") => {
+            for day in DAYS {
+                println!("Day {}", day.day());
+            }
+            Ok(true)
+        }
+        _ => {
+            println!("{USAGE}");
+            exit(2);
+        }
+    };
+
+    match result {
+        Ok(true) => {}
+        Ok(false) => exit(1),
+        Err(message) => {
+            eprintln!("{message}\n\n{USAGE}");
+            exit(2);
+        }
+    }
+}
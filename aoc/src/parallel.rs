@@ -0,0 +1,282 @@
+use std::{
+    any::Any,
+    collections::VecDeque,
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::{Arc, Condvar, Mutex},
+    thread,
+};
+
+use common::{report::Record, Puzzle};
+
+type Input = Arc<Box<dyn Any + Send + Sync>>;
+
+/// Everything that came out of running one day.
+#[derive(Debug, Default)]
+pub struct DayResult {
+    /// Records of the parts that were solved, in the order they were asked for.
+    pub records: Vec<Record>,
+    pub errors: Vec<String>,
+}
+
+/// A [`DayResult`] that is still being filled in, with a place for every part.
+struct Slot {
+    records: Vec<Option<Record>>,
+    errors: Vec<String>,
+}
+
+enum Task {
+    /// Load and parse the input of the day at this index.
+    Parse(usize),
+    /// Solve the part at the second index for the day at the first.
+    Solve(usize, usize, Input),
+}
+
+struct Queue {
+    tasks: VecDeque<Task>,
+    /// Tasks that have been taken but not finished, and may still queue more.
+    busy: usize,
+}
+
+struct Scheduler<'a> {
+    days: &'a [&'a dyn Puzzle],
+    parts: &'a [u8],
+    load: &'a (dyn Fn(u8) -> Result<String, String> + Sync),
+    queue: Mutex<Queue>,
+    ready: Condvar,
+    results: Vec<Mutex<Slot>>,
+}
+
+impl Scheduler<'_> {
+    fn next(&self) -> Option<Task> {
+        let mut queue = self.queue.lock().unwrap();
+
+        loop {
+            if let Some(task) = queue.tasks.pop_front() {
+                queue.busy += 1;
+                return Some(task);
+            }
+
+            if queue.busy == 0 {
+                return None;
+            }
+
+            queue = self.ready.wait(queue).unwrap();
+        }
+    }
+
+    fn finish(&self, follow_ups: Vec<Task>) {
+        let mut queue = self.queue.lock().unwrap();
+
+        queue.busy -= 1;
+        queue.tasks.extend(follow_ups);
+
+        self.ready.notify_all();
+    }
+
+    fn fail(&self, index: usize, error: String) {
+        self.results[index].lock().unwrap().errors.push(error);
+    }
+
+    fn work(&self) {
+        while let Some(task) = self.next() {
+            let follow_ups = match task {
+                Task::Parse(index) => self.parse(index),
+                Task::Solve(index, part, input) => {
+                    self.solve(index, part, &input);
+                    Vec::new()
+                }
+            };
+
+            self.finish(follow_ups);
+        }
+    }
+
+    fn parse(&self, index: usize) -> Vec<Task> {
+        let day = self.days[index];
+
+        let parsed = catch_unwind(AssertUnwindSafe(|| {
+            let input = (self.load)(day.day())?;
+
+            day.parse_input(&input)
+                .map_err(|error| format!("Could not parse input for day {}: {error}", day.day()))
+        }));
+
+        match parsed {
+            Ok(Ok(input)) => {
+                let input = Arc::new(input);
+
+                (0..self.parts.len())
+                    .map(|part| Task::Solve(index, part, Arc::clone(&input)))
+                    .collect()
+            }
+            Ok(Err(error)) => {
+                self.fail(index, error);
+                Vec::new()
+            }
+            Err(payload) => {
+                let message = panic_message(payload);
+                self.fail(
+                    index,
+                    format!("Day {} panicked while parsing: {message}", day.day()),
+                );
+                Vec::new()
+            }
+        }
+    }
+
+    fn solve(&self, index: usize, part: usize, input: &Input) {
+        let day = self.days[index];
+        let number = self.parts[part];
+
+        match catch_unwind(AssertUnwindSafe(|| day.record(number, &***input))) {
+            Ok(record) => self.results[index].lock().unwrap().records[part] = Some(record),
+            Err(payload) => {
+                let message = panic_message(payload);
+                self.fail(
+                    index,
+                    format!("Day {} part {number} panicked: {message}", day.day()),
+                );
+            }
+        }
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast_ref::<&str>() {
+            Some(message) => message.to_string(),
+            None => "unknown cause".to_string(),
+        },
+    }
+}
+
+/// Solves `parts` of every day on `workers` threads. Each day is parsed once,
+/// after which its parts are solved concurrently. A day that fails or panics
+/// only affects its own result, and results come back in the order of `days`
+/// no matter which finished first.
+pub fn run(
+    days: &[&dyn Puzzle],
+    parts: &[u8],
+    workers: usize,
+    load: &(dyn Fn(u8) -> Result<String, String> + Sync),
+) -> Vec<DayResult> {
+    let scheduler = Scheduler {
+        days,
+        parts,
+        load,
+        queue: Mutex::new(Queue {
+            tasks: (0..days.len()).map(Task::Parse).collect(),
+            busy: 0,
+        }),
+        ready: Condvar::new(),
+        results: days
+            .iter()
+            .map(|_| {
+                Mutex::new(Slot {
+                    records: vec![None; parts.len()],
+                    errors: Vec::new(),
+                })
+            })
+            .collect(),
+    };
+
+    thread::scope(|scope| {
+        for _ in 0..workers.max(1) {
+            scope.spawn(|| scheduler.work());
+        }
+    });
+
+    scheduler
+        .results
+        .into_iter()
+        .map(|slot| {
+            let slot = slot.into_inner().unwrap();
+
+            DayResult {
+                records: slot.records.into_iter().flatten().collect(),
+                errors: slot.errors,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{ParseError, Puzzle, Solution};
+
+    use crate::parallel::run;
+
+    /// Answers with its input, panicking in part 2 if the input says so.
+    struct Echo<const DAY: u8>;
+
+    impl<const DAY: u8> Solution for Echo<DAY> {
+        const DAY: u8 = DAY;
+
+        type Input = String;
+        type Part1 = String;
+        type Part2 = String;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            match input {
+                "bad" => Err(ParseError::line(0, input, "not good")),
+                _ => Ok(input.to_string()),
+            }
+        }
+
+        fn part1(input: &Self::Input) -> Self::Part1 {
+            input.clone()
+        }
+
+        fn part2(input: &Self::Input) -> Self::Part2 {
+            match input.as_str() {
+                "panic" => panic!("as requested"),
+                _ => input.to_uppercase(),
+            }
+        }
+    }
+
+    const DAYS: &[&dyn Puzzle] = &[&Echo::<1>, &Echo::<2>, &Echo::<3>, &Echo::<4>];
+
+    fn load(day: u8) -> Result<String, String> {
+        match day {
+            1 => Ok("one".to_string()),
+            2 => Ok("panic".to_string()),
+            3 => Ok("bad".to_string()),
+            _ => Err(format!("No input for day {day}")),
+        }
+    }
+
+    #[test]
+    fn results_keep_the_order_of_the_days() {
+        for workers in [1, 4] {
+            let days = [DAYS[0], DAYS[1], DAYS[0]];
+            let results = run(&days, &[2, 1], workers, &load);
+            let records = results.iter().flat_map(|result| &result.records);
+            let order = records
+                .map(|record| (record.day, record.answer.as_str()))
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                order,
+                [(1, "ONE"), (1, "one"), (2, "panic"), (1, "ONE"), (1, "one")]
+            );
+        }
+    }
+
+    #[test]
+    fn failures_are_isolated_per_task() {
+        let results = run(DAYS, &[1, 2], 3, &load);
+
+        assert_eq!(results[0].records.len(), 2);
+        assert!(results[0].errors.is_empty());
+
+        assert_eq!(results[1].records.len(), 1);
+        assert_eq!(results[1].errors, ["Day 2 part 2 panicked: as requested"]);
+
+        assert!(results[2].records.is_empty());
+        assert!(results[2].errors[0].starts_with("Could not parse input for day 3"));
+
+        assert_eq!(results[3].errors, ["No input for day 4"]);
+    }
+}
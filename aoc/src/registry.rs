@@ -0,0 +1,32 @@
+use common::Puzzle;
+
+pub const DAYS: &[&dyn Puzzle] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+];
+
+pub fn find(number: u8) -> Option<&'static dyn Puzzle> {
+    DAYS.iter().copied().find(|day| day.day() == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry::DAYS;
+
+    #[test]
+    fn days_are_registered_in_order() {
+        assert!(DAYS.windows(2).all(|pair| pair[0].day() < pair[1].day()));
+    }
+}
@@ -0,0 +1,194 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Files of the `dayxx` template, relative to the crate root.
+const TEMPLATE: &[(&str, &str)] = &[
+    ("Cargo.toml", include_str!("../../dayxx/Cargo.toml")),
+    ("src/lib.rs", include_str!("../../dayxx/src/lib.rs")),
+    ("src/main.rs", include_str!("../../dayxx/src/main.rs")),
+    ("tests/api.rs", include_str!("../../dayxx/tests/api.rs")),
+];
+
+/// Fills in the template for `day`: crate and type names, and the day number
+/// used to find its input.
+pub fn render(template: &str, day: u8) -> String {
+    template
+        .replace("const DAY: u8 = 0;", &format!("const DAY: u8 = {day};"))
+        .replace("dayxx", &format!("day{day:02}"))
+        .replace("Dayxx", &format!("Day{day:02}"))
+}
+
+/// Adds `name` to the `members` list of the workspace manifest, keeping the
+/// list sorted.
+pub fn add_member(manifest: &str, name: &str) -> Result<String, String> {
+    let start = manifest
+        .find("members = [")
+        .map(|start| start + "members = [".len())
+        .ok_or("Cargo.toml has no workspace members list")?;
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .ok_or("Cargo.toml has an unterminated members list")?;
+
+    let mut members = manifest[start..end]
+        .split(',')
+        .map(|member| member.trim().trim_matches('"'))
+        .filter(|member| !member.is_empty())
+        .collect::<Vec<_>>();
+
+    if members.contains(&name) {
+        return Err(format!("{name} is already a workspace member"));
+    }
+
+    members.push(name);
+    members.sort();
+
+    let members = members
+        .iter()
+        .map(|member| format!("  \"{member}\""))
+        .collect::<Vec<_>>()
+        .join(",\n");
+
+    Ok(format!(
+        "{}\n{members}\n{}",
+        &manifest[..start],
+        &manifest[end..]
+    ))
+}
+
+/// Creates `dayNN/` under the workspace at `root` and registers it as a
+/// member. Nothing is written if the day already exists.
+pub fn scaffold(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    let name = format!("day{day:02}");
+    let dir = root.join(&name);
+    let manifest_path = root.join("Cargo.toml");
+
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    let manifest = fs::read_to_string(&manifest_path)
+        .map_err(|error| format!("Could not read {}: {error}", manifest_path.display()))?;
+    let manifest = add_member(&manifest, &name)?;
+
+    let mut created = Vec::new();
+
+    for (file, template) in TEMPLATE {
+        let path = dir.join(file);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|error| format!("Could not create {}: {error}", parent.display()))?;
+        }
+
+        fs::write(&path, render(template, day))
+            .map_err(|error| format!("Could not write {}: {error}", path.display()))?;
+        created.push(path);
+    }
+
+    fs::write(&manifest_path, manifest)
+        .map_err(|error| format!("Could not write {}: {error}", manifest_path.display()))?;
+
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::Path, process};
+
+    use crate::scaffold::{add_member, render, scaffold};
+
+    #[test]
+    fn template_is_renamed() {
+        let lib = render("use dayxx::Dayxx;\n    const DAY: u8 = 0;", 7);
+
+        assert_eq!(lib, "use day07::Day07;\n    const DAY: u8 = 7;");
+    }
+
+    #[test]
+    fn members_stay_sorted() {
+        let manifest = "[workspace]\nmembers = [\n  \"aoc\",\n  \"day14\"\n]\n";
+
+        assert_eq!(
+            add_member(manifest, "day02").unwrap(),
+            "[workspace]\nmembers = [\n  \"aoc\",\n  \"day02\",\n  \"day14\"\n]\n"
+        );
+        assert!(add_member(manifest, "day14").is_err());
+    }
+
+    #[test]
+    fn existing_days_are_not_overwritten() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", process::id()));
+        let manifest = "[workspace]\nmembers = [\n  \"aoc\"\n]\n";
+
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("Cargo.toml"), manifest).unwrap();
+
+        let created = scaffold(&root, 15).unwrap();
+        let main = fs::read_to_string(root.join("day15/src/main.rs")).unwrap();
+
+        assert_eq!(created.len(), 4);
+        assert!(main.contains("use day15::Day15;"));
+        assert!(fs::read_to_string(root.join("Cargo.toml"))
+            .unwrap()
+            .contains("\"day15\""));
+
+        fs::write(root.join("day15/src/lib.rs"), "// work in progress").unwrap();
+
+        assert!(scaffold(&root, 15).is_err());
+        assert_eq!(
+            fs::read_to_string(root.join("day15/src/lib.rs")).unwrap(),
+            "// work in progress"
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    fn copy_dir(from: &Path, to: &Path) {
+        fs::create_dir_all(to).unwrap();
+
+        for entry in fs::read_dir(from).unwrap() {
+            let entry = entry.unwrap();
+
+            match entry.file_type().unwrap().is_dir() {
+                true => copy_dir(&entry.path(), &to.join(entry.file_name())),
+                false => {
+                    fs::copy(entry.path(), to.join(entry.file_name())).unwrap();
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn scaffolded_days_build_and_pass_their_tests() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-build-{}", process::id()));
+        let common = Path::new(env!("CARGO_MANIFEST_DIR")).join("../common");
+        let manifest = "[workspace]\nresolver = \"2\"\nmembers = [\n  \"common\"\n]\n";
+
+        copy_dir(&common.join("src"), &root.join("common/src"));
+        fs::copy(common.join("Cargo.toml"), root.join("common/Cargo.toml")).unwrap();
+        fs::write(root.join("Cargo.toml"), manifest).unwrap();
+
+        scaffold(&root, 15).unwrap();
+
+        let status = process::Command::new(env!("CARGO"))
+            .args([
+                "test",
+                "--offline",
+                "--quiet",
+                "-p",
+                "day15",
+                "--manifest-path",
+            ])
+            .arg(root.join("Cargo.toml"))
+            .env("CARGO_TARGET_DIR", root.join("target"))
+            .status()
+            .unwrap();
+
+        fs::remove_dir_all(&root).unwrap();
+
+        assert!(status.success());
+    }
+}
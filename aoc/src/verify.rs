@@ -0,0 +1,272 @@
+use std::fmt::Write;
+
+use common::{
+    report::{csv_field, json_string, Format},
+    Puzzle,
+};
+
+use crate::answers::{self, Answers, Status};
+
+/// The result of checking one part of a day, or the whole day when it could
+/// not be solved at all.
+#[derive(Debug, PartialEq)]
+pub struct Check {
+    pub day: u8,
+    pub part: Option<u8>,
+    pub outcome: Outcome,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    NoInput,
+    Unparseable { error: String },
+    Pass { answer: String },
+    Fail { answer: String, expected: String },
+    Recorded { answer: String },
+    Missing { answer: String },
+}
+
+impl Outcome {
+    fn name(&self) -> &'static str {
+        match self {
+            Outcome::NoInput => "no input",
+            Outcome::Unparseable { .. } | Outcome::Fail { .. } => "FAIL",
+            Outcome::Pass { .. } => "pass",
+            Outcome::Recorded { .. } => "recorded",
+            Outcome::Missing { .. } => "missing",
+        }
+    }
+
+    /// Whether this outcome should make `aoc verify` fail.
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Outcome::Unparseable { .. } | Outcome::Fail { .. })
+    }
+
+    /// The answer that was found, or why there is none.
+    fn detail(&self) -> &str {
+        match self {
+            Outcome::NoInput => "",
+            Outcome::Unparseable { error } => error,
+            Outcome::Pass { answer }
+            | Outcome::Fail { answer, .. }
+            | Outcome::Recorded { answer }
+            | Outcome::Missing { answer } => answer,
+        }
+    }
+
+    fn expected(&self) -> Option<&str> {
+        match self {
+            Outcome::Fail { expected, .. } => Some(expected),
+            _ => None,
+        }
+    }
+}
+
+/// Solves both parts of `day` and checks them against `answers`. With
+/// `record`, answers that are not known yet are added to `answers`.
+pub fn verify(
+    day: &dyn Puzzle,
+    input: Option<&str>,
+    answers: &mut Answers,
+    record: bool,
+) -> Vec<Check> {
+    let whole_day = |outcome| {
+        vec![Check {
+            day: day.day(),
+            part: None,
+            outcome,
+        }]
+    };
+
+    // Days without an input cannot be checked, but are not a regression
+    let Some(input) = input else {
+        return whole_day(Outcome::NoInput);
+    };
+
+    let input = match day.parse_input(input) {
+        Ok(input) => input,
+        Err(error) => {
+            return whole_day(Outcome::Unparseable {
+                error: error.to_string(),
+            })
+        }
+    };
+
+    [1, 2]
+        .into_iter()
+        .map(|part| {
+            let answer = day.solve(part, &*input);
+
+            let outcome = match answers.check(day.day(), part, &answer) {
+                Status::Pass => Outcome::Pass { answer },
+                Status::Fail { expected } => Outcome::Fail { answer, expected },
+                Status::Missing if record => {
+                    answers.insert(day.day(), part, answer.clone());
+                    Outcome::Recorded { answer }
+                }
+                Status::Missing => Outcome::Missing { answer },
+            };
+
+            Check {
+                day: day.day(),
+                part: Some(part),
+                outcome,
+            }
+        })
+        .collect()
+}
+
+pub fn render(checks: &[Check], format: Format) -> String {
+    match format {
+        Format::Text => text(checks),
+        Format::Json => json(checks),
+        Format::Csv => csv(checks),
+    }
+}
+
+fn text(checks: &[Check]) -> String {
+    let mut table = "Day  Part  Result   Answer\n---  ----  -------  ------\n".to_string();
+
+    for check in checks {
+        let part = check.part.map(|part| part.to_string()).unwrap_or_default();
+        let mut result = format!(
+            "{:<8} {}",
+            check.outcome.name(),
+            answers::escape(check.outcome.detail())
+        );
+
+        if let Some(expected) = check.outcome.expected() {
+            write!(result, " (expected {})", answers::escape(expected)).unwrap();
+        }
+
+        writeln!(table, "{:>3}  {part:>4}  {}", check.day, result.trim_end()).unwrap();
+    }
+
+    table
+}
+
+/// An array with one object per check. Checks of a whole day have a `null`
+/// part, and only failed parts have an expected answer.
+fn json(checks: &[Check]) -> String {
+    let objects = checks
+        .iter()
+        .map(|check| {
+            let part = check.part.map_or("null".to_string(), |part| part.to_string());
+            let expected = check
+                .outcome
+                .expected()
+                .map_or("null".to_string(), json_string);
+
+            format!(
+                "  {{\"day\": {}, \"part\": {part}, \"result\": {}, \"answer\": {}, \"expected\": {expected}}}",
+                check.day,
+                json_string(check.outcome.name()),
+                json_string(check.outcome.detail()),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    match objects.is_empty() {
+        true => "[]\n".to_string(),
+        false => format!("[\n{}\n]\n", objects.join(",\n")),
+    }
+}
+
+fn csv(checks: &[Check]) -> String {
+    let mut csv = "day,part,result,answer,expected\n".to_string();
+
+    for check in checks {
+        writeln!(
+            csv,
+            "{},{},{},{},{}",
+            check.day,
+            check.part.map(|part| part.to_string()).unwrap_or_default(),
+            csv_field(check.outcome.name()),
+            csv_field(check.outcome.detail()),
+            csv_field(check.outcome.expected().unwrap_or("")),
+        )
+        .unwrap();
+    }
+
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use common::report::Format;
+
+    use crate::{
+        answers::Answers,
+        verify::{render, verify, Check, Outcome},
+    };
+
+    const INPUT: &str = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+
+    #[test]
+    fn missing_answers_are_only_recorded_when_asked() {
+        let mut answers = Answers::parse("06 1 7\n").unwrap();
+
+        let checks = verify(&day06::Day06, Some(INPUT), &mut answers, false);
+
+        assert_eq!(
+            checks[0].outcome,
+            Outcome::Pass {
+                answer: "7".to_string()
+            }
+        );
+        assert_eq!(
+            checks[1].outcome,
+            Outcome::Missing {
+                answer: "19".to_string()
+            }
+        );
+        assert_eq!(answers.get(6, 2), None);
+
+        let checks = verify(&day06::Day06, Some(INPUT), &mut answers, true);
+
+        assert!(matches!(checks[1].outcome, Outcome::Recorded { .. }));
+        assert_eq!(answers.get(6, 2), Some("19"));
+    }
+
+    #[test]
+    fn days_without_input_are_not_failures() {
+        let checks = verify(&day06::Day06, None, &mut Answers::default(), false);
+
+        assert_eq!(
+            checks,
+            [Check {
+                day: 6,
+                part: None,
+                outcome: Outcome::NoInput
+            }]
+        );
+        assert!(!checks[0].outcome.is_failure());
+    }
+
+    #[test]
+    fn checks_are_rendered_in_every_format() {
+        let mut answers = Answers::parse("06 1 8\n").unwrap();
+        let mut checks = verify(&day06::Day06, Some(INPUT), &mut answers, false);
+        checks.extend(verify(&day07::Day07, None, &mut answers, false));
+
+        assert_eq!(
+            render(&checks, Format::Text),
+            "Day  Part  Result   Answer\n\
+             ---  ----  -------  ------\n  \
+               6     1  FAIL     7 (expected 8)\n  \
+               6     2  missing  19\n  \
+               7        no input\n"
+        );
+        assert_eq!(
+            render(&checks, Format::Json),
+            "[\n  \
+             {\"day\": 6, \"part\": 1, \"result\": \"FAIL\", \"answer\": \"7\", \"expected\": \"8\"},\n  \
+             {\"day\": 6, \"part\": 2, \"result\": \"missing\", \"answer\": \"19\", \"expected\": null},\n  \
+             {\"day\": 7, \"part\": null, \"result\": \"no input\", \"answer\": \"\", \"expected\": null}\n]\n"
+        );
+        assert_eq!(
+            render(&checks, Format::Csv),
+            "day,part,result,answer,expected\n6,1,FAIL,7,8\n6,2,missing,19,\n7,,no input,,\n"
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use std::{env, process::exit};
+
+use crate::report::Format;
+
+/// Command line arguments shared by every day's binary.
+#[derive(Debug, Default, PartialEq)]
+pub struct Args {
+    /// Value of `--input`, `-` meaning stdin.
+    pub input: Option<String>,
+    /// Value of `--format`.
+    pub format: Format,
+}
+
+impl Args {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" | "-i" => match args.next() {
+                    Some(input) => parsed.input = Some(input),
+                    None => return Err(format!("{arg} needs a path, or - for stdin")),
+                },
+                "--format" | "-f" => match args.next() {
+                    Some(format) => parsed.format = format.parse()?,
+                    None => return Err(format!("{arg} needs one of json, csv or text")),
+                },
+                "-" => parsed.input = Some(arg),
+                _ => return Err(format!("Unknown argument \"{arg}\"")),
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    /// Parses the arguments of the running binary, exiting with a usage
+    /// message if they are invalid.
+    pub fn from_env() -> Self {
+        let mut args = env::args();
+        let name = args.next().unwrap_or_default();
+
+        match Args::parse(args) {
+            Ok(args) => args,
+            Err(message) => {
+                eprintln!(
+                    "{message}\n\nUsage: {name} [--input <PATH|->] [--format <json|csv|text>]"
+                );
+                exit(2);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{args::Args, report::Format};
+
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        Args::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn input_can_be_a_path_or_stdin() {
+        assert_eq!(
+            parse(&["--input", "foo"]).unwrap().input.as_deref(),
+            Some("foo")
+        );
+        assert_eq!(parse(&["-i", "-"]).unwrap().input.as_deref(), Some("-"));
+        assert_eq!(parse(&["-"]).unwrap().input.as_deref(), Some("-"));
+        assert_eq!(parse(&[]).unwrap().input, None);
+    }
+
+    #[test]
+    fn format_defaults_to_text() {
+        assert_eq!(parse(&[]).unwrap().format, Format::Text);
+        assert_eq!(parse(&["-f", "csv"]).unwrap().format, Format::Csv);
+        assert!(parse(&["--format", "yaml"]).is_err());
+    }
+
+    #[test]
+    fn bad_arguments_are_rejected() {
+        assert!(parse(&["--input"]).is_err());
+        assert!(parse(&["--frobnicate"]).is_err());
+    }
+}
@@ -0,0 +1,76 @@
+use std::fmt::{self, Display};
+
+/// Points at the part of a puzzle input that could not be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number in the input.
+    pub line: usize,
+    /// 1-based column of the offending text, counted in characters.
+    pub column: usize,
+    /// The offending text itself.
+    pub text: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    /// Creates an error for `token` on the line at 0-based `index` in the
+    /// input. `token` should be a slice of `line`, so that its column can be
+    /// worked out from where it sits in the line.
+    pub fn new(index: usize, line: &str, token: &str, reason: impl Into<String>) -> Self {
+        let start = line.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&offset| offset + token.len() <= line.len() && line.is_char_boundary(offset))
+            .or_else(|| line.find(token))
+            .unwrap_or(0);
+
+        Self {
+            line: index + 1,
+            column: line[..offset].chars().count() + 1,
+            text: token.to_string(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Creates an error covering the whole line at 0-based `index`.
+    pub fn line(index: usize, line: &str, reason: impl Into<String>) -> Self {
+        Self::new(index, line, line, reason)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {} (found \"{}\")",
+            self.line, self.column, self.reason, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::ParseError;
+
+    #[test]
+    fn column_is_taken_from_the_token_slice() {
+        let line = "move 3 from x to 1";
+        let error = ParseError::new(4, line, &line[12..13], "expected a stack number");
+
+        assert_eq!((error.line, error.column), (5, 13));
+        assert_eq!(error.text, "x");
+        assert_eq!(
+            error.to_string(),
+            "line 5, column 13: expected a stack number (found \"x\")"
+        );
+    }
+
+    #[test]
+    fn owned_tokens_are_looked_up_in_the_line() {
+        let error = ParseError::new(0, "1,2 -> 3,y", "y", "expected a number");
+
+        assert_eq!(error.column, 10);
+    }
+}
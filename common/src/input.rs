@@ -0,0 +1,143 @@
+use std::{
+    env,
+    fmt::{self, Display},
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable pointing at a directory laid out like the workspace,
+/// i.e. containing `dayNN/input` (or `dayNN.txt`) for every day.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound { day: u8, tried: Vec<PathBuf> },
+    Unreadable { path: PathBuf, error: io::Error },
+    Stdin { day: u8, error: io::Error },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound { day, tried } => {
+                write!(f, "Could not find input file for day {day}, tried:")?;
+
+                for path in tried {
+                    write!(f, "\n    {}", path.display())?;
+                }
+
+                Ok(())
+            }
+            InputError::Unreadable { path, error } => {
+                write!(f, "Could not read input file {}: {error}", path.display())
+            }
+            InputError::Stdin { day, error } => {
+                write!(f, "Could not read input for day {day} from stdin: {error}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Every path the input for `day` is looked for at when no explicit source is
+/// given, in order of preference.
+pub fn candidates(day: u8) -> Vec<PathBuf> {
+    candidates_from(
+        day,
+        env::var_os(INPUT_DIR_VAR).map(PathBuf::from),
+        env::current_dir().ok(),
+    )
+}
+
+fn candidates_from(day: u8, input_dir: Option<PathBuf>, cwd: Option<PathBuf>) -> Vec<PathBuf> {
+    let name = format!("day{day:02}");
+    let mut candidates = Vec::new();
+
+    if let Some(dir) = input_dir {
+        candidates.push(dir.join(&name).join("input"));
+        candidates.push(dir.join(format!("{name}.txt")));
+    }
+
+    // Running from inside the day's own crate, e.g. `cargo run` in `day07/`
+    if cwd.is_some_and(|cwd| cwd.file_name().is_some_and(|dir| dir == name.as_str())) {
+        candidates.push(PathBuf::from("input"));
+    }
+
+    candidates.push(Path::new(&name).join("input"));
+
+    candidates
+}
+
+/// Loads the input for `day`. `source` is the value given to `--input`, where
+/// `-` means stdin; without one, every path from [`candidates`] is tried.
+pub fn load(day: u8, source: Option<&str>) -> Result<String, InputError> {
+    match source {
+        Some("-") => {
+            let mut input = String::new();
+
+            match io::stdin().read_to_string(&mut input) {
+                Ok(_) => Ok(input),
+                Err(error) => Err(InputError::Stdin { day, error }),
+            }
+        }
+        Some(path) => read(day, vec![PathBuf::from(path)]),
+        None => read(day, candidates(day)),
+    }
+}
+
+fn read(day: u8, paths: Vec<PathBuf>) -> Result<String, InputError> {
+    for path in &paths {
+        match fs::read_to_string(path) {
+            Ok(input) => return Ok(input),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => continue,
+            Err(error) => {
+                return Err(InputError::Unreadable {
+                    path: path.clone(),
+                    error,
+                })
+            }
+        }
+    }
+
+    Err(InputError::NotFound { day, tried: paths })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::input::{candidates_from, load};
+
+    #[test]
+    fn input_dir_is_tried_before_the_defaults() {
+        let candidates = candidates_from(7, Some(PathBuf::from("/inputs")), None);
+
+        assert_eq!(
+            candidates,
+            vec![
+                PathBuf::from("/inputs/day07/input"),
+                PathBuf::from("/inputs/day07.txt"),
+                PathBuf::from("day07/input"),
+            ]
+        );
+    }
+
+    #[test]
+    fn bare_input_is_only_tried_inside_the_day_crate() {
+        let inside = candidates_from(7, None, Some(PathBuf::from("/aoc/day07")));
+        let outside = candidates_from(7, None, Some(PathBuf::from("/aoc")));
+
+        assert_eq!(inside[0], PathBuf::from("input"));
+        assert!(!outside.contains(&PathBuf::from("input")));
+    }
+
+    #[test]
+    fn missing_input_names_every_path_tried() {
+        let error = load(7, Some("does/not/exist")).unwrap_err().to_string();
+
+        assert!(error.contains("day 7"));
+        assert!(error.contains("does/not/exist"));
+    }
+}
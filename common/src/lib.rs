@@ -0,0 +1,165 @@
+use std::{any::Any, fmt::Display, process::exit};
+
+use args::Args;
+use report::Format;
+
+pub mod args;
+mod error;
+pub mod input;
+pub mod report;
+
+pub use error::ParseError;
+use report::Record;
+
+/// A day of the calendar: the input is parsed once, and both parts are
+/// solved from the parsed representation.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+}
+
+/// Type-erased view of a [`Solution`], so that days with different input and
+/// answer types can be stored next to each other and driven by the same code.
+pub trait Puzzle: Sync {
+    fn day(&self) -> u8;
+    fn parse_input(&self, input: &str) -> Result<Box<dyn Any + Send + Sync>, ParseError>;
+    fn solve(&self, part: u8, input: &dyn Any) -> String;
+    /// Like [`Puzzle::solve`], but also records the answer type and timing.
+    fn record(&self, part: u8, input: &dyn Any) -> Record;
+}
+
+impl<S> Puzzle for S
+where
+    S: Solution + Sync,
+    S::Input: Send + Sync + 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse_input(&self, input: &str) -> Result<Box<dyn Any + Send + Sync>, ParseError> {
+        match S::parse(input) {
+            Ok(input) => Ok(Box::new(input)),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn solve(&self, part: u8, input: &dyn Any) -> String {
+        match part {
+            1 => S::part1(downcast::<S>(input)).to_string(),
+            2 => S::part2(downcast::<S>(input)).to_string(),
+            _ => panic!("Day {} has no part {part}", S::DAY),
+        }
+    }
+
+    fn record(&self, part: u8, input: &dyn Any) -> Record {
+        Record::solve::<S>(part, downcast::<S>(input))
+    }
+}
+
+/// Everything a day's binary does: loads the input named on the command line,
+/// parses it, and prints both answers in the requested format. `text` prints
+/// them for people, so that every day can describe its answers its own way.
+pub fn run<S: Solution>(text: impl FnOnce(&S::Input)) {
+    let args = Args::from_env();
+
+    let input = match input::load(S::DAY, args.input.as_deref()) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{error}");
+            exit(1);
+        }
+    };
+
+    let input = match S::parse(&input) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("Could not parse input: {error}");
+            exit(1);
+        }
+    };
+
+    match args.format {
+        Format::Text => text(&input),
+        format => print!("{}", format.render(&report::records::<S>(&input))),
+    }
+}
+
+fn downcast<S>(input: &dyn Any) -> &S::Input
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    match input.downcast_ref::<S::Input>() {
+        Some(input) => input,
+        None => panic!("Input for day {} was parsed by another day", S::DAY),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ParseError, Puzzle, Solution};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+
+        type Input = Vec<u32>;
+        type Part1 = u32;
+        type Part2 = String;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input
+                .split(',')
+                .map(|n| {
+                    n.parse()
+                        .map_err(|_| ParseError::new(0, input, n, "expected a number"))
+                })
+                .collect()
+        }
+
+        fn part1(input: &Self::Input) -> Self::Part1 {
+            input.iter().sum()
+        }
+
+        fn part2(input: &Self::Input) -> Self::Part2 {
+            format!("{} numbers", input.len())
+        }
+    }
+
+    #[test]
+    fn puzzle_parses_once_and_solves_both_parts() {
+        let puzzle: &dyn Puzzle = &Sum;
+        let input = puzzle.parse_input("1,2,3").unwrap();
+
+        assert_eq!(puzzle.day(), 0);
+        assert_eq!(puzzle.solve(1, &*input), "6");
+        assert_eq!(puzzle.solve(2, &*input), "3 numbers");
+    }
+
+    #[test]
+    fn records_know_the_answer_type() {
+        let puzzle: &dyn Puzzle = &Sum;
+        let input = puzzle.parse_input("1,2,3").unwrap();
+        let record = puzzle.record(2, &*input);
+
+        assert_eq!((record.day, record.part), (0, 2));
+        assert_eq!(record.answer, "3 numbers");
+        assert_eq!(record.kind, "String");
+    }
+
+    #[test]
+    fn parse_errors_are_passed_through() {
+        let error = Sum.parse_input("1,x,3").err().unwrap();
+
+        assert_eq!((error.line, error.column), (1, 3));
+    }
+}
@@ -0,0 +1,278 @@
+use std::{
+    any::type_name,
+    fmt::Write,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::Solution;
+
+/// How results are printed.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Format {
+    /// Prose for people.
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "\"{format}\" is not a format, expected json, csv or text"
+            )),
+        }
+    }
+}
+
+/// The answer to one part of a day, and how long it took to find.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    /// Rust type of the answer, e.g. `usize` or `String`.
+    pub kind: &'static str,
+    pub elapsed: Duration,
+}
+
+impl Record {
+    /// Solves `part` of `S`, timing only the solver itself.
+    pub fn solve<S: Solution>(part: u8, input: &S::Input) -> Self {
+        let start = Instant::now();
+        let (answer, kind) = match part {
+            1 => (S::part1(input).to_string(), short_type_name::<S::Part1>()),
+            2 => (S::part2(input).to_string(), short_type_name::<S::Part2>()),
+            _ => panic!("Day {} has no part {part}", S::DAY),
+        };
+        let elapsed = start.elapsed();
+
+        Self {
+            day: S::DAY,
+            part,
+            answer,
+            kind,
+            elapsed,
+        }
+    }
+}
+
+/// Solves both parts of `S`.
+pub fn records<S: Solution>(input: &S::Input) -> Vec<Record> {
+    vec![Record::solve::<S>(1, input), Record::solve::<S>(2, input)]
+}
+
+/// `type_name` without the module path, so `alloc::string::String` becomes
+/// `String`. Generic types are left alone, as their arguments have paths too.
+pub fn short_type_name<T>() -> &'static str {
+    let name = type_name::<T>();
+
+    match name.contains('<') {
+        true => name,
+        false => name.rsplit("::").next().unwrap_or(name),
+    }
+}
+
+impl Format {
+    pub fn render(&self, records: &[Record]) -> String {
+        match self {
+            Format::Text => text(records),
+            Format::Json => json(records),
+            Format::Csv => csv(records),
+        }
+    }
+}
+
+/// A table with one row per part. Answers spanning several lines, like a
+/// rendered screen, continue on the following rows.
+fn text(records: &[Record]) -> String {
+    let times = records
+        .iter()
+        .map(|record| format!("{:.2?}", record.elapsed))
+        .collect::<Vec<_>>();
+    let time_width = times.iter().map(|time| time.chars().count()).max();
+    let time_width = time_width.unwrap_or(0).max("Time".len());
+    let answer_width = records
+        .iter()
+        .flat_map(|record| record.answer.lines())
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+
+    let mut table = format!("Day  Part  {:>time_width$}  Answer\n", "Time");
+    writeln!(
+        table,
+        "---  ----  {}  {}",
+        "-".repeat(time_width),
+        "-".repeat(answer_width)
+    )
+    .unwrap();
+
+    for (record, time) in records.iter().zip(&times) {
+        let mut lines = record.answer.lines();
+
+        writeln!(
+            table,
+            "{:>3}  {:>4}  {time:>time_width$}  {}",
+            record.day,
+            record.part,
+            lines.next().unwrap_or("")
+        )
+        .unwrap();
+
+        for line in lines {
+            writeln!(table, "{:>3}  {:>4}  {:>time_width$}  {line}", "", "", "").unwrap();
+        }
+    }
+
+    table
+}
+
+/// An array with one object per part. Answers are always strings, as not
+/// every consumer can hold a `u64` as a number without losing precision; the
+/// `type` field says what they were.
+fn json(records: &[Record]) -> String {
+    let objects = records
+        .iter()
+        .map(|record| {
+            format!(
+                "  {{\"day\": {}, \"part\": {}, \"answer\": {}, \"type\": {}, \"time_ns\": {}}}",
+                record.day,
+                record.part,
+                json_string(&record.answer),
+                json_string(record.kind),
+                record.elapsed.as_nanos()
+            )
+        })
+        .collect::<Vec<_>>();
+
+    match objects.is_empty() {
+        true => "[]\n".to_string(),
+        false => format!("[\n{}\n]\n", objects.join(",\n")),
+    }
+}
+
+fn csv(records: &[Record]) -> String {
+    let mut csv = "day,part,answer,type,time_ns\n".to_string();
+
+    for record in records {
+        writeln!(
+            csv,
+            "{},{},{},{},{}",
+            record.day,
+            record.part,
+            csv_field(&record.answer),
+            csv_field(record.kind),
+            record.elapsed.as_nanos()
+        )
+        .unwrap();
+    }
+
+    csv
+}
+
+pub fn json_string(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len() + 2);
+
+    escaped.push('"');
+
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+
+    escaped
+}
+
+pub fn csv_field(text: &str) -> String {
+    match text.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", text.replace('"', "\"\"")),
+        false => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::report::{json_string, short_type_name, Format, Record};
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record {
+                day: 6,
+                part: 1,
+                answer: "7".to_string(),
+                kind: "usize",
+                elapsed: Duration::from_nanos(1500),
+            },
+            Record {
+                day: 10,
+                part: 2,
+                answer: "#.\n.#".to_string(),
+                kind: "String",
+                elapsed: Duration::from_micros(20),
+            },
+        ]
+    }
+
+    #[test]
+    fn formats_are_parsed_by_name() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("csv".parse(), Ok(Format::Csv));
+        assert_eq!("text".parse(), Ok(Format::Text));
+        assert!("xml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn type_names_lose_their_path() {
+        assert_eq!(short_type_name::<String>(), "String");
+        assert_eq!(short_type_name::<usize>(), "usize");
+    }
+
+    #[test]
+    fn json_has_one_object_per_part() {
+        assert_eq!(
+            Format::Json.render(&records()),
+            "[\n  {\"day\": 6, \"part\": 1, \"answer\": \"7\", \"type\": \"usize\", \"time_ns\": 1500},\n  \
+             {\"day\": 10, \"part\": 2, \"answer\": \"#.\\n.#\", \"type\": \"String\", \"time_ns\": 20000}\n]\n"
+        );
+        assert_eq!(Format::Json.render(&[]), "[]\n");
+        assert_eq!(json_string("say \"hi\"\u{1}"), "\"say \\\"hi\\\"\\u0001\"");
+    }
+
+    #[test]
+    fn csv_quotes_multiline_answers() {
+        assert_eq!(
+            Format::Csv.render(&records()),
+            "day,part,answer,type,time_ns\n6,1,7,usize,1500\n10,2,\"#.\n.#\",String,20000\n"
+        );
+    }
+
+    #[test]
+    fn text_continues_multiline_answers() {
+        let table = Format::Text.render(&records());
+        let lines = table.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines[0], "Day  Part     Time  Answer");
+        assert_eq!(lines[2], "  6     1   1.50µs  7");
+        assert_eq!(lines[3], " 10     2  20.00µs  #.");
+    }
+}
@@ -0,0 +1,78 @@
+use common::{ParseError, Solution};
+
+pub mod part1 {
+    pub fn solve(calories: &[usize]) -> usize {
+        calories.iter().copied().max().unwrap()
+    }
+}
+
+pub mod part2 {
+    pub fn solve(calories: &[usize]) -> usize {
+        let mut calories = calories.to_vec();
+
+        calories.sort_by(|a, b| b.cmp(a));
+
+        calories[0..3].iter().sum()
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut elves = vec![0];
+
+        for (index, line) in input.lines().enumerate() {
+            if line.is_empty() {
+                elves.push(0);
+                continue;
+            }
+
+            match line.parse::<usize>() {
+                Ok(calories) => *elves.last_mut().unwrap() += calories,
+                Err(_) => return Err(ParseError::line(index, line, "expected a calorie count")),
+            }
+        }
+
+        Ok(elves)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day01;
+    use common::{ParseError, Solution};
+    const TEST_INPUT: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+
+    #[test]
+    fn validate_part1() {
+        assert_eq!(Day01::part1(&Day01::parse(TEST_INPUT).unwrap()), 24000);
+    }
+
+    #[test]
+    fn validate_part2() {
+        assert_eq!(Day01::part2(&Day01::parse(TEST_INPUT).unwrap()), 45000);
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_offending_line() {
+        assert_eq!(
+            Day01::parse("1000\n\n20O0"),
+            Err(ParseError::line(2, "20O0", "expected a calorie count"))
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use common::Solution;
+use day01::{part1, part2, Day01};
+
+#[test]
+fn parse_sums_each_elf() {
+    assert_eq!(Day01::parse("1\n2\n\n3\n\n4\n5\n6"), Ok(vec![3, 3, 15]));
+}
+
+#[test]
+fn parts_solve_parsed_calories() {
+    let calories = vec![6000, 4000, 11000, 24000, 10000];
+
+    assert_eq!(part1::solve(&calories), 24000);
+    assert_eq!(part2::solve(&calories), 45000);
+}
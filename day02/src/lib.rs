@@ -0,0 +1,166 @@
+use common::{ParseError, Solution};
+
+pub type Round = (char, char);
+
+pub mod part1 {
+    use crate::Round;
+
+    pub fn solve(rounds: &[Round]) -> usize {
+        let mut score = 0usize;
+
+        for &(opponent, me) in rounds {
+            match me {
+                'X' => match opponent {
+                    'A' => score += 4,
+                    'B' => score += 1,
+                    'C' => score += 7,
+                    _ => {}
+                },
+
+                'Y' => match opponent {
+                    'A' => score += 8,
+                    'B' => score += 5,
+                    'C' => score += 2,
+                    _ => {}
+                },
+
+                'Z' => match opponent {
+                    'A' => score += 3,
+                    'B' => score += 9,
+                    'C' => score += 6,
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+
+        score
+    }
+}
+
+pub mod part2 {
+    use crate::Round;
+
+    pub fn solve(rounds: &[Round]) -> usize {
+        let mut score = 0usize;
+
+        for &(opponent, result) in rounds {
+            match opponent {
+                'A' => match result {
+                    'X' => score += 3,
+                    'Y' => score += 4,
+                    'Z' => score += 5,
+                    _ => {}
+                },
+                'B' => match result {
+                    'X' => score += 1,
+                    'Y' => score += 5,
+                    'Z' => score += 9,
+                    _ => {}
+                },
+                'C' => match result {
+                    'X' => score += 2,
+                    'Y' => score += 6,
+                    'Z' => score += 7,
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+
+        score
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Round>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                let mut split = line.split(' ');
+
+                let opponent = match split.next() {
+                    Some(token @ ("A" | "B" | "C")) => token.chars().next().unwrap(),
+                    token => {
+                        return Err(ParseError::new(
+                            index,
+                            line,
+                            token.unwrap_or(line),
+                            "expected the opponent's shape to be A, B or C",
+                        ))
+                    }
+                };
+
+                let me = match split.next() {
+                    Some(token @ ("X" | "Y" | "Z")) => token.chars().next().unwrap(),
+                    token => {
+                        return Err(ParseError::new(
+                            index,
+                            line,
+                            token.unwrap_or(&line[line.len()..]),
+                            "expected the second column to be X, Y or Z",
+                        ))
+                    }
+                };
+
+                if let Some(token) = split.next() {
+                    return Err(ParseError::new(
+                        index,
+                        line,
+                        token,
+                        "unexpected extra column",
+                    ));
+                }
+
+                Ok((opponent, me))
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day02;
+    use common::{ParseError, Solution};
+    const TEST_INPUT: &str = "A Y\nB X\nC Z";
+
+    #[test]
+    fn validate_part1() {
+        assert_eq!(Day02::part1(&Day02::parse(TEST_INPUT).unwrap()), 15);
+    }
+
+    #[test]
+    fn validate_part2() {
+        assert_eq!(Day02::part2(&Day02::parse(TEST_INPUT).unwrap()), 12);
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_offending_column() {
+        assert_eq!(
+            Day02::parse("A Y\nB W"),
+            Err(ParseError::new(
+                1,
+                "B W",
+                "W",
+                "expected the second column to be X, Y or Z"
+            ))
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use common::Solution;
+use day02::{part1, part2, Day02};
+
+#[test]
+fn parse_reads_one_round_per_line() {
+    assert_eq!(Day02::parse("A Y\nB X"), Ok(vec![('A', 'Y'), ('B', 'X')]));
+}
+
+#[test]
+fn parts_score_rounds() {
+    let rounds = [('A', 'Y'), ('B', 'X'), ('C', 'Z')];
+
+    assert_eq!(part1::solve(&rounds), 15);
+    assert_eq!(part2::solve(&rounds), 12);
+}
@@ -0,0 +1,147 @@
+use common::{ParseError, Solution};
+
+pub fn char_to_priority(char: char) -> usize {
+    if char.is_ascii_lowercase() {
+        (char as u32 - 'a' as u32 + 1) as usize
+    } else {
+        (char as u32 - 'A' as u32 + 27) as usize
+    }
+}
+
+pub mod part1 {
+    use crate::char_to_priority;
+
+    pub fn solve(rucksacks: &[String]) -> usize {
+        let mut score = 0usize;
+
+        for line in rucksacks {
+            let first = line[0..line.len() / 2].to_string();
+            let second = line[line.len() / 2..line.len()].to_string();
+
+            for letter in first.chars() {
+                if second.contains(letter) {
+                    score += char_to_priority(letter);
+                    break;
+                }
+            }
+        }
+
+        score
+    }
+}
+
+pub mod part2 {
+    use crate::char_to_priority;
+
+    pub fn solve(rucksacks: &[String]) -> usize {
+        let mut score = 0;
+        let mut lines = rucksacks.iter();
+
+        while let Some(first) = lines.next() {
+            let second = match lines.next() {
+                Some(line) => line,
+                None => {
+                    break;
+                }
+            };
+
+            let third = match lines.next() {
+                Some(line) => line,
+                None => {
+                    break;
+                }
+            };
+
+            for letter in first.chars() {
+                if second.contains(letter) && third.contains(letter) {
+                    score += char_to_priority(letter);
+                    break;
+                }
+            }
+        }
+
+        score
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                if let Some((offset, item)) =
+                    line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic())
+                {
+                    let item = &line[offset..offset + item.len_utf8()];
+                    return Err(ParseError::new(index, line, item, "items must be letters"));
+                }
+
+                if line.len() % 2 != 0 {
+                    return Err(ParseError::line(
+                        index,
+                        line,
+                        "rucksack must hold an even number of items",
+                    ));
+                }
+
+                Ok(line.to_string())
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day03;
+    use common::{ParseError, Solution};
+    const TEST_INPUT: &str =
+"vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+    #[test]
+    fn validate_part1() {
+        assert_eq!(Day03::part1(&Day03::parse(TEST_INPUT).unwrap()), 157);
+    }
+
+    #[test]
+    fn validate_part2() {
+        assert_eq!(Day03::part2(&Day03::parse(TEST_INPUT).unwrap()), 70);
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_offending_item() {
+        let error = Day03::parse("abAB\nab1B").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.text, "1");
+        assert_eq!(
+            Day03::parse("abc"),
+            Err(ParseError::line(
+                0,
+                "abc",
+                "rucksack must hold an even number of items"
+            ))
+        );
+    }
+}
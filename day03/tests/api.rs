@@ -0,0 +1,20 @@
+use common::Solution;
+use day03::{char_to_priority, Day03};
+
+#[test]
+fn priorities_follow_the_alphabet() {
+    assert_eq!(char_to_priority('a'), 1);
+    assert_eq!(char_to_priority('z'), 26);
+    assert_eq!(char_to_priority('A'), 27);
+    assert_eq!(char_to_priority('Z'), 52);
+}
+
+#[test]
+fn groups_of_three_share_a_badge() {
+    let rucksacks = Day03::parse(
+        "vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwg",
+    )
+    .unwrap();
+
+    assert_eq!(Day03::part2(&rucksacks), char_to_priority('r'));
+}
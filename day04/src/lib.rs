@@ -0,0 +1,133 @@
+use common::{ParseError, Solution};
+
+pub type Assignment = (usize, usize, usize, usize);
+
+/// Parses the line at 0-based `index` into both elves' section ranges.
+pub fn parse_line(index: usize, line: &str) -> Result<Assignment, ParseError> {
+    let mut values: Vec<usize> = Vec::new();
+    let groups: Vec<&str> = line.split(',').collect();
+
+    if groups.len() != 2 {
+        return Err(ParseError::line(
+            index,
+            line,
+            "expected two comma separated ranges",
+        ));
+    }
+
+    for group in groups {
+        let ends: Vec<&str> = group.split('-').collect();
+
+        if ends.len() != 2 {
+            return Err(ParseError::new(
+                index,
+                line,
+                group,
+                "expected a range like 2-4",
+            ));
+        }
+
+        for end in ends {
+            match end.parse::<usize>() {
+                Ok(value) => values.push(value),
+                Err(_) => {
+                    return Err(ParseError::new(
+                        index,
+                        line,
+                        end,
+                        "expected a section number",
+                    ))
+                }
+            }
+        }
+    }
+
+    Ok((values[0], values[1], values[2], values[3]))
+}
+
+pub mod part1 {
+    use crate::Assignment;
+
+    pub fn solve(pairs: &[Assignment]) -> usize {
+        let mut overlapping_pair_count = 0usize;
+
+        for &(first_start, first_end, second_start, second_end) in pairs {
+            if (first_start >= second_start && first_end <= second_end)
+                || (second_start >= first_start && second_end <= first_end)
+            {
+                overlapping_pair_count += 1;
+            }
+        }
+
+        overlapping_pair_count
+    }
+}
+
+pub mod part2 {
+    use crate::Assignment;
+
+    pub fn solve(pairs: &[Assignment]) -> usize {
+        let mut overlapping_pair_count = 0usize;
+
+        for &(first_start, first_end, second_start, second_end) in pairs {
+            if (first_start..=first_end).any(|n| [second_start, second_end].contains(&n))
+                || (second_start..=second_end).any(|n| [first_start, first_end].contains(&n))
+            {
+                overlapping_pair_count += 1;
+            }
+        }
+
+        overlapping_pair_count
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Assignment>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| parse_line(index, line))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day04;
+    use common::Solution;
+    const TEST_INPUT: &str = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8";
+
+    #[test]
+    fn validate_part1() {
+        assert_eq!(Day04::part1(&Day04::parse(TEST_INPUT).unwrap()), 2);
+    }
+
+    #[test]
+    fn validate_part2() {
+        assert_eq!(Day04::part2(&Day04::parse(TEST_INPUT).unwrap()), 4);
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_offending_section() {
+        let error = Day04::parse("2-4,6-8\n2-3,4-x").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 7));
+        assert_eq!(error.text, "x");
+    }
+}
@@ -0,0 +1,17 @@
+use day04::{parse_line, part1, part2};
+
+#[test]
+fn parse_line_reads_both_ranges() {
+    assert_eq!(parse_line(0, "2-4,6-8"), Ok((2, 4, 6, 8)));
+}
+
+#[test]
+fn containment_implies_overlap() {
+    let pairs = [
+        parse_line(0, "2-8,3-7").unwrap(),
+        parse_line(1, "5-7,7-9").unwrap(),
+    ];
+
+    assert_eq!(part1::solve(&pairs), 1);
+    assert_eq!(part2::solve(&pairs), 2);
+}
@@ -0,0 +1,196 @@
+use common::{ParseError, Solution};
+
+#[derive(Clone, Copy, Debug)]
+pub struct Move {
+    pub count: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+#[derive(Debug)]
+pub struct Procedure {
+    pub stacks: Vec<Vec<char>>,
+    pub moves: Vec<Move>,
+}
+
+pub fn parse_initial_state(input: &str) -> Vec<Vec<char>> {
+    let mut state: Vec<Vec<char>> = Vec::new();
+
+    input
+        .lines()
+        .filter(|line| line.contains('['))
+        .for_each(|line| {
+            line.char_indices()
+                .filter(|(idx, _)| *idx > 0 && (idx - 1) % 4 == 0)
+                .for_each(|(idx, c)| {
+                    if (idx - 1) / 4 == state.len() {
+                        state.push(Vec::new());
+                    }
+
+                    if c != ' ' {
+                        state[(idx - 1) / 4].push(c);
+                    }
+                })
+        });
+
+    state.iter_mut().for_each(|stack| stack.reverse());
+
+    state
+}
+
+/// Parses every `move` line of the input, checking that the stacks it moves
+/// between are among the first `stack_count` stacks.
+pub fn parse_moves(input: &str, stack_count: usize) -> Result<Vec<Move>, ParseError> {
+    let stack = |index: usize, line: &str, token: &str| match token.parse::<usize>() {
+        Ok(stack) if (1..=stack_count).contains(&stack) => Ok(stack - 1),
+        _ => Err(ParseError::new(
+            index,
+            line,
+            token,
+            format!("expected a stack number between 1 and {stack_count}"),
+        )),
+    };
+
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| line.starts_with("move"))
+        .map(|(index, line)| {
+            let split: Vec<&str> = line.split_ascii_whitespace().collect();
+
+            if split.len() != 6 || split[2] != "from" || split[4] != "to" {
+                return Err(ParseError::line(
+                    index,
+                    line,
+                    "expected a move like \"move 1 from 2 to 1\"",
+                ));
+            }
+
+            Ok(Move {
+                count: match split[1].parse::<usize>() {
+                    Ok(count) => count,
+                    Err(_) => {
+                        return Err(ParseError::new(
+                            index,
+                            line,
+                            split[1],
+                            "expected a number of crates",
+                        ))
+                    }
+                },
+                from: stack(index, line, split[3])?,
+                to: stack(index, line, split[5])?,
+            })
+        })
+        .collect()
+}
+
+pub mod part1 {
+    use crate::Procedure;
+
+    pub fn solve(procedure: &Procedure) -> String {
+        let mut state = procedure.stacks.clone();
+
+        procedure.moves.iter().for_each(|step| {
+            for _ in 0..step.count {
+                let c = state[step.from].pop().unwrap();
+                state[step.to].push(c);
+            }
+        });
+
+        let mut result = "".to_string();
+
+        for mut stack in state {
+            result = format!("{result}{}", stack.pop().unwrap_or('\0'));
+        }
+
+        result
+    }
+}
+
+pub mod part2 {
+    use crate::Procedure;
+
+    pub fn solve(procedure: &Procedure) -> String {
+        let mut state = procedure.stacks.clone();
+
+        procedure.moves.iter().for_each(|step| {
+            let mut crane: Vec<char> = Vec::new();
+
+            for _ in 0..step.count {
+                let c = state[step.from].pop().unwrap();
+                crane.push(c);
+            }
+
+            for _ in 0..step.count {
+                state[step.to].push(crane.pop().unwrap());
+            }
+        });
+
+        let mut result = "".to_string();
+
+        for mut stack in state {
+            result = format!("{result}{}", stack.pop().unwrap_or(' '));
+        }
+
+        result
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Procedure;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let stacks = parse_initial_state(input);
+        let moves = parse_moves(input, stacks.len())?;
+
+        Ok(Procedure { stacks, moves })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day05;
+    use common::Solution;
+    const TEST_INPUT: &str = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+
+    #[test]
+    fn validate_part1() {
+        assert_eq!(Day05::part1(&Day05::parse(TEST_INPUT).unwrap()), "CMZ");
+    }
+
+    #[test]
+    fn validate_part2() {
+        assert_eq!(Day05::part2(&Day05::parse(TEST_INPUT).unwrap()), "MCD");
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_offending_stack() {
+        let error = Day05::parse(&TEST_INPUT.replace("to 3", "to 4")).unwrap_err();
+
+        assert_eq!((error.line, error.column), (7, 18));
+        assert_eq!(error.text, "4");
+    }
+}
@@ -0,0 +1,35 @@
+use day05::{parse_initial_state, parse_moves, part1, part2, Procedure};
+
+const DRAWING: &str = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1";
+
+#[test]
+fn stacks_are_read_bottom_up() {
+    assert_eq!(
+        parse_initial_state(DRAWING),
+        vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]
+    );
+}
+
+#[test]
+fn moves_are_zero_indexed() {
+    let moves = parse_moves(DRAWING, 3).unwrap();
+
+    assert_eq!(moves.len(), 1);
+    assert_eq!((moves[0].count, moves[0].from, moves[0].to), (1, 1, 0));
+}
+
+#[test]
+fn parts_rearrange_a_procedure() {
+    let procedure = Procedure {
+        stacks: vec![vec!['A', 'B', 'C'], vec![]],
+        moves: parse_moves("move 2 from 1 to 2", 2).unwrap(),
+    };
+
+    assert_eq!(part1::solve(&procedure), "AB");
+    assert_eq!(part2::solve(&procedure), "AC");
+}
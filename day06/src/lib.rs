@@ -0,0 +1,86 @@
+use std::collections::HashSet;
+
+use common::{ParseError, Solution};
+
+pub fn incredible_solution(input: &str, window_width: usize) -> usize {
+    input
+        .chars()
+        .enumerate()
+        .collect::<Vec<(usize, char)>>()
+        .windows(window_width)
+        .fold(0, |accumulator, window| {
+            let mut set = HashSet::new();
+            if accumulator == 0 && window.iter().all(|&(_, character)| set.insert(character)) {
+                window.last().unwrap().0 + 1
+            } else {
+                accumulator
+            }
+        })
+}
+
+pub mod part1 {
+    use crate::incredible_solution;
+
+    pub fn solve(datastream: &str) -> usize {
+        incredible_solution(datastream, 4)
+    }
+}
+
+pub mod part2 {
+    use crate::incredible_solution;
+
+    pub fn solve(datastream: &str) -> usize {
+        incredible_solution(datastream, 14)
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day06;
+    use common::Solution;
+    const TEST_INPUT_1: &str = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+    const TEST_INPUT_2: &str = "bvwbjplbgvbhsrlpgdmjqwftvncz";
+    const TEST_INPUT_3: &str = "nppdvjthqldpwncqszvftbrmjlhg";
+    const TEST_INPUT_4: &str = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg";
+    const TEST_INPUT_5: &str = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw";
+
+    #[test]
+    fn validate_part1() {
+        assert_eq!(Day06::part1(&Day06::parse(TEST_INPUT_1).unwrap()), 7);
+        assert_eq!(Day06::part1(&Day06::parse(TEST_INPUT_2).unwrap()), 5);
+        assert_eq!(Day06::part1(&Day06::parse(TEST_INPUT_3).unwrap()), 6);
+        assert_eq!(Day06::part1(&Day06::parse(TEST_INPUT_4).unwrap()), 10);
+        assert_eq!(Day06::part1(&Day06::parse(TEST_INPUT_5).unwrap()), 11);
+    }
+
+    #[test]
+    fn validate_part2() {
+        assert_eq!(Day06::part2(&Day06::parse(TEST_INPUT_1).unwrap()), 19);
+        assert_eq!(Day06::part2(&Day06::parse(TEST_INPUT_2).unwrap()), 23);
+        assert_eq!(Day06::part2(&Day06::parse(TEST_INPUT_3).unwrap()), 23);
+        assert_eq!(Day06::part2(&Day06::parse(TEST_INPUT_4).unwrap()), 29);
+        assert_eq!(Day06::part2(&Day06::parse(TEST_INPUT_5).unwrap()), 26);
+    }
+}
@@ -0,0 +1,12 @@
+use day06::incredible_solution;
+
+#[test]
+fn marker_ends_after_first_unique_window() {
+    assert_eq!(incredible_solution("aabcd", 4), 5);
+    assert_eq!(incredible_solution("abcd", 4), 4);
+}
+
+#[test]
+fn no_marker_gives_zero() {
+    assert_eq!(incredible_solution("aaaaaa", 2), 0);
+}
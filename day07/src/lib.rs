@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+
+use common::{ParseError, Solution};
+
+type Stack<'a> = Vec::<&'a str>;
+
+trait Reset {
+    fn reset(&mut self);
+}
+
+impl <'a>Reset for Stack<'a> {
+    fn reset(&mut self) {
+        self.clear();
+        self.push("/");
+    }
+}
+
+pub fn disk_usage(input: &str) -> Result<HashMap<String, usize>, ParseError> {
+    let mut usage: HashMap<String, usize> = HashMap::new();
+    let mut dirstack: Stack = Stack::new();
+
+    usage.insert("/".into(), 0);
+    dirstack.reset();
+
+    for (index, line) in input.lines().enumerate() {
+        let tokens = line.split_whitespace().collect::<Stack>();
+
+        match tokens[..] {
+            ["$", "cd", dir] => match dir {
+                "/" => {
+                    dirstack.reset();
+                }
+                ".." => {
+                    dirstack.pop();
+                }
+                _ => {
+                    dirstack.push(dir);
+                    usage.entry(dirstack.join("/")).or_insert(0);
+                }
+            },
+            ["$", "ls"] => continue,
+            ["$", command, ..] => {
+                return Err(ParseError::new(index, line, command, "unknown command"))
+            }
+            ["dir", _] => continue,
+            [size, _] => {
+                let size = match size.parse::<usize>() {
+                    Ok(size) => size,
+                    Err(_) => {
+                        return Err(ParseError::new(index, line, size, "expected a file size"))
+                    }
+                };
+
+                for i in 1..=dirstack.len() {
+                    let dir = dirstack[0..i].join("/");
+                    usage.entry(dir).and_modify(|total| *total += size);
+                }
+            }
+            _ => {
+                return Err(ParseError::line(
+                    index,
+                    line,
+                    "expected a command, a directory or a file",
+                ))
+            }
+        }
+    }
+
+    Ok(usage)
+}
+
+pub mod part1 {
+    use std::collections::HashMap;
+
+    pub fn solve(usage: &HashMap<String, usize>) -> usize {
+        usage.values().filter(|&&size| size <= 100_000).sum()
+    }
+}
+
+pub mod part2 {
+    use std::collections::HashMap;
+
+    pub fn solve(usage: &HashMap<String, usize>) -> usize {
+        let total_usage = match usage.get("/") {
+            Some(usage) => *usage,
+            None => {
+                panic!("/ has no size for some reason")
+            }
+        };
+
+        *usage
+            .values()
+            .filter(|&size| total_usage - size < 40_000_000)
+            .min()
+            .unwrap()
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = HashMap<String, usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        disk_usage(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day07;
+    use common::Solution;
+    const TEST_INPUT: &str = "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+
+    #[test]
+    fn validate_part1() {
+        assert_eq!(Day07::part1(&Day07::parse(TEST_INPUT).unwrap()), 95437);
+    }
+
+    #[test]
+    fn validate_part2() {
+        assert_eq!(Day07::part2(&Day07::parse(TEST_INPUT).unwrap()), 24933642);
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_offending_token() {
+        let error = Day07::parse("$ cd /\n$ ls\n12k b.txt").unwrap_err();
+
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.text, "12k");
+
+        let error = Day07::parse("$ cd /\n$ rm -rf").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+}
@@ -0,0 +1,16 @@
+use day07::disk_usage;
+
+#[test]
+fn sizes_count_towards_every_parent() {
+    let usage = disk_usage("$ cd /\n$ ls\ndir a\n10 b\n$ cd a\n$ ls\n5 c").unwrap();
+
+    assert_eq!(usage["/"], 15);
+    assert_eq!(usage["//a"], 5);
+}
+
+#[test]
+fn unvisited_directories_are_not_listed() {
+    let usage = disk_usage("$ cd /\n$ ls\ndir a\n10 b").unwrap();
+
+    assert_eq!(usage.len(), 1);
+}
@@ -0,0 +1,66 @@
+//! Draws which trees are visible and how scenic every tree is, and
+//! optionally writes both as images.
+//!
+//!     cargo run -p day08 --example render
+//!     cargo run -p day08 --example render -- --pgm scores.pgm --ppm forest.ppm
+
+use std::{env, fs, process::exit};
+
+use common::{input, Solution};
+use day08::{part1, part2, render, Day08};
+
+fn write(path: &str, image: &[u8]) {
+    if let Err(error) = fs::write(path, image) {
+        eprintln!("Could not write {path}: {error}");
+        exit(1);
+    }
+}
+
+fn main() {
+    let (mut pgm, mut ppm) = (None, None);
+    let mut args = env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match (arg.as_str(), args.next()) {
+            ("--pgm", Some(path)) => pgm = Some(path),
+            ("--ppm", Some(path)) => ppm = Some(path),
+            _ => {
+                eprintln!("Usage: render [--pgm <PATH>] [--ppm <PATH>]");
+                exit(2);
+            }
+        }
+    }
+
+    let input = match input::load(Day08::DAY, None) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{error}");
+            exit(1);
+        }
+    };
+
+    let forest = match Day08::parse(&input) {
+        Ok(forest) => forest,
+        Err(error) => {
+            eprintln!("Could not parse input: {error}");
+            exit(1);
+        }
+    };
+
+    let visibility = part1::visibility(&forest);
+    let scores = part2::scenic_scores(&forest);
+    let best = part2::best_treehouses(&forest, 1)
+        .first()
+        .map(|treehouse| treehouse.position);
+
+    println!("Visible from how many edges:\n{visibility}\n");
+    println!("Scenic scores:\n{}", render::heatmap(&scores));
+
+    if let Some(path) = pgm {
+        write(&path, &render::pgm(&scores));
+    }
+
+    if let Some(path) = ppm {
+        write(&path, &render::ppm(&forest, &visibility, best));
+    }
+}
@@ -0,0 +1,49 @@
+//! Times both parts on generated forests of growing size, to check that the
+//! time spent per tree stays flat.
+//!
+//! `cargo run --release -p day08 --example scaling [MAX_SIZE]`
+
+use std::{env, time::Instant};
+
+use day08::{generate_forest, part1, part2};
+
+fn main() {
+    let max_size = match env::args().nth(1) {
+        Some(size) => size.parse().expect("Size must be a number"),
+        None => 2000,
+    };
+
+    println!(
+        "{:>9}  {:>12}  {:>10}  {:>12}  {:>10}",
+        "Size", "Part 1", "ns/tree", "Part 2", "ns/tree"
+    );
+
+    let mut size = 125;
+
+    while size <= max_size {
+        let forest = generate_forest(size, size, size as u64);
+        let trees = (size * size) as f64;
+
+        let start = Instant::now();
+        let visible = part1::solve(&forest);
+        let first = start.elapsed();
+
+        let start = Instant::now();
+        let scenic = part2::solve(&forest);
+        let second = start.elapsed();
+
+        println!(
+            "{:>9}  {:>12.2?}  {:>10.1}  {:>12.2?}  {:>10.1}",
+            format!("{size}x{size}"),
+            first,
+            first.as_nanos() as f64 / trees,
+            second,
+            second.as_nanos() as f64 / trees,
+        );
+
+        // Keep the answers alive so the work is not optimised away
+        assert!(visible <= size * size && scenic < usize::MAX);
+
+        size *= 2;
+    }
+}
@@ -0,0 +1,392 @@
+use std::fmt::{self, Display};
+
+use common::{ParseError, Solution};
+use grid::{Direction, Grid, Position};
+
+pub mod render;
+
+/// Tree heights, indexed by their position in the forest. Forests can be any
+/// rectangle, so both parts look at `width()` and `height()` separately.
+pub type Forest = Grid<u8>;
+
+/// Which edges of the forest a tree can be seen from.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Visibility {
+    pub up: bool,
+    pub down: bool,
+    pub left: bool,
+    pub right: bool,
+}
+
+impl Visibility {
+    /// Whether the tree can be seen when looking from the edge `direction`
+    /// points towards, so `Direction::Up` means from the top edge.
+    pub fn from_edge(&self, direction: Direction) -> bool {
+        match direction {
+            Direction::Up => self.up,
+            Direction::Down => self.down,
+            Direction::Left => self.left,
+            Direction::Right => self.right,
+            _ => false,
+        }
+    }
+
+    fn set(&mut self, direction: Direction) {
+        match direction {
+            Direction::Up => self.up = true,
+            Direction::Down => self.down = true,
+            Direction::Left => self.left = true,
+            Direction::Right => self.right = true,
+            _ => {}
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.directions() > 0
+    }
+
+    /// The number of edges the tree can be seen from.
+    pub fn directions(&self) -> usize {
+        [self.up, self.down, self.left, self.right]
+            .into_iter()
+            .filter(|&visible| visible)
+            .count()
+    }
+}
+
+/// `.` for a hidden tree, otherwise the number of edges it is visible from.
+impl Display for Visibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.directions() {
+            0 => write!(f, "."),
+            directions => write!(f, "{directions}"),
+        }
+    }
+}
+
+pub fn input_to_forest(input: &str) -> Result<Forest, ParseError> {
+    Grid::parse(input, |c| match c.to_digit(10) {
+        Some(height) => Ok(height as u8),
+        None => Err("tree heights must be digits"),
+    })
+}
+
+/// Every row or column of the forest as seen when looking towards
+/// `direction`, starting at the edge it looks at. Walking a line in order,
+/// every tree only needs to know about the trees already walked past.
+pub fn lines(forest: &Forest, direction: Direction) -> Vec<Vec<Position>> {
+    let (width, height) = (forest.width(), forest.height());
+
+    // A forest without rows or columns has no edge to start from
+    if width == 0 || height == 0 {
+        return Vec::new();
+    }
+
+    let starts = match direction {
+        Direction::Left => (0..height).map(|y| Position::new(0, y)).collect(),
+        Direction::Right => (0..height).map(|y| Position::new(width - 1, y)).collect(),
+        Direction::Up => (0..width).map(|x| Position::new(x, 0)).collect(),
+        Direction::Down => (0..width).map(|x| Position::new(x, height - 1)).collect(),
+        _ => Vec::new(),
+    };
+
+    starts
+        .into_iter()
+        .map(|start| {
+            std::iter::once(start)
+                .chain(forest.ray(start, direction.opposite()))
+                .collect()
+        })
+        .collect()
+}
+
+/// A forest of pseudo-random heights, the same for every `seed`, for
+/// benchmarks and tests that need more trees than the example has.
+pub fn generate_forest(width: usize, height: usize, seed: u64) -> Forest {
+    let mut state = seed;
+    let cells = (0..width * height)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((state >> 33) % 10) as u8
+        })
+        .collect();
+
+    Grid::from_cells(width, cells).unwrap()
+}
+
+pub mod part1 {
+    use grid::{Direction, Grid};
+
+    use crate::{lines, Forest, Visibility};
+
+    /// Which trees can be seen from outside the forest when looking from
+    /// `direction`, found by keeping the tallest tree so far along each line.
+    pub fn visible_from(forest: &Forest, direction: Direction) -> Grid<bool> {
+        let mut visible = Grid::new(forest.width(), forest.height(), false);
+
+        for line in lines(forest, direction) {
+            let mut tallest = None;
+
+            for tree in line {
+                if tallest.is_none_or(|tallest| forest[tree] > tallest) {
+                    visible[tree] = true;
+                    tallest = Some(forest[tree]);
+                }
+            }
+        }
+
+        visible
+    }
+
+    /// Every edge each tree can be seen from.
+    pub fn visibility(forest: &Forest) -> Grid<Visibility> {
+        let mut visibility = Grid::new(forest.width(), forest.height(), Visibility::default());
+
+        for direction in Direction::ORTHOGONAL {
+            let visible = visible_from(forest, direction);
+
+            for tree in forest.positions() {
+                if visible[tree] {
+                    visibility[tree].set(direction);
+                }
+            }
+        }
+
+        visibility
+    }
+
+    pub fn solve(forest: &Forest) -> usize {
+        visibility(forest)
+            .iter()
+            .filter(|(_, visibility)| visibility.is_visible())
+            .count()
+    }
+}
+
+pub mod part2 {
+    use std::cmp::Reverse;
+
+    use grid::{Direction, Grid, Position};
+
+    use crate::{lines, Forest};
+
+    /// How many trees can be seen from `tree` looking towards `direction`,
+    /// counting the first tree that is at least as tall.
+    pub fn viewing_distance(forest: &Forest, tree: Position, direction: Direction) -> usize {
+        let mut distance = 0;
+
+        for other in forest.ray(tree, direction) {
+            distance += 1;
+
+            if forest[other] >= forest[tree] {
+                break;
+            }
+        }
+
+        distance
+    }
+
+    /// [`viewing_distance`] for every tree at once. Each line keeps a stack of
+    /// the trees that could still block the view, in decreasing height, so
+    /// every tree is pushed and popped at most once.
+    pub fn viewing_distances(forest: &Forest, direction: Direction) -> Grid<usize> {
+        let mut distances = Grid::new(forest.width(), forest.height(), 0);
+
+        for line in lines(forest, direction) {
+            let mut blockers: Vec<(usize, u8)> = Vec::new();
+
+            for (index, &tree) in line.iter().enumerate() {
+                let height = forest[tree];
+
+                while blockers.last().is_some_and(|&(_, other)| other < height) {
+                    blockers.pop();
+                }
+
+                distances[tree] = match blockers.last() {
+                    Some(&(blocker, _)) => index - blocker,
+                    None => index,
+                };
+
+                blockers.push((index, height));
+            }
+        }
+
+        distances
+    }
+
+    pub fn scenic_scores(forest: &Forest) -> Grid<usize> {
+        let mut scores = Grid::new(forest.width(), forest.height(), 1);
+
+        for direction in Direction::ORTHOGONAL {
+            let distances = viewing_distances(forest, direction);
+
+            for tree in forest.positions() {
+                scores[tree] *= distances[tree];
+            }
+        }
+
+        scores
+    }
+
+    /// A candidate spot for the treehouse and what can be seen from it.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Treehouse {
+        pub position: Position,
+        /// Viewing distances in the order of [`Direction::ORTHOGONAL`].
+        pub distances: [usize; 4],
+        pub score: usize,
+    }
+
+    impl Treehouse {
+        pub fn distance(&self, direction: Direction) -> usize {
+            Direction::ORTHOGONAL
+                .iter()
+                .position(|&orthogonal| orthogonal == direction)
+                .map_or(0, |index| self.distances[index])
+        }
+    }
+
+    /// The `k` trees with the highest scenic scores, best first. Trees with
+    /// equal scores come in reading order, top to bottom and left to right.
+    pub fn best_treehouses(forest: &Forest, k: usize) -> Vec<Treehouse> {
+        let distances = Direction::ORTHOGONAL.map(|direction| viewing_distances(forest, direction));
+
+        let mut treehouses = forest
+            .positions()
+            .map(|position| {
+                let distances = distances.each_ref().map(|distances| distances[position]);
+
+                Treehouse {
+                    position,
+                    distances,
+                    score: distances.iter().product(),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        treehouses.sort_by_key(|treehouse| {
+            (
+                Reverse(treehouse.score),
+                treehouse.position.y,
+                treehouse.position.x,
+            )
+        });
+        treehouses.truncate(k);
+
+        treehouses
+    }
+
+    pub fn solve(forest: &Forest) -> usize {
+        scenic_scores(forest)
+            .iter()
+            .map(|(_, &score)| score)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Forest;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_to_forest(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day08;
+    use common::Solution;
+    const TEST_INPUT: &str = "30373
+25512
+65332
+33549
+35390
+";
+
+    #[test]
+    fn validate_part1() {
+        assert_eq!(Day08::part1(&Day08::parse(TEST_INPUT).unwrap()), 21);
+    }
+
+    #[test]
+    fn validate_part2() {
+        assert_eq!(Day08::part2(&Day08::parse(TEST_INPUT).unwrap()), 8);
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_offending_tree() {
+        let error = Day08::parse("303\n2x5").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.text, "x");
+    }
+
+    #[test]
+    fn rectangular_forests_are_solved_both_ways_round() {
+        let wide = Day08::parse("30373\n25512\n65332").unwrap();
+        let tall = Day08::parse("326\n055\n353\n713\n322").unwrap();
+
+        assert_eq!((wide.width(), wide.height()), (5, 3));
+        assert_eq!((Day08::part1(&wide), Day08::part2(&wide)), (14, 2));
+        assert_eq!((Day08::part1(&tall), Day08::part2(&tall)), (14, 2));
+    }
+
+    #[test]
+    fn ragged_forests_are_rejected() {
+        let error = Day08::parse("30373\n2551\n65332").unwrap_err();
+
+        assert_eq!(error.line, 2);
+        assert_eq!(error.reason, "row is 4 wide, but the first row is 5 wide");
+    }
+
+    #[test]
+    fn fast_solutions_agree_with_looking_down_every_line() {
+        use crate::{generate_forest, part1, part2};
+        use grid::Direction;
+
+        for (seed, (width, height)) in [(1, 1), (7, 3), (12, 12), (30, 17)].into_iter().enumerate()
+        {
+            let forest = generate_forest(width, height, seed as u64);
+            let visible = forest
+                .positions()
+                .filter(|&tree| {
+                    Direction::ORTHOGONAL.iter().any(|&direction| {
+                        forest
+                            .ray(tree, direction)
+                            .all(|other| forest[other] < forest[tree])
+                    })
+                })
+                .count();
+
+            assert_eq!(part1::solve(&forest), visible);
+
+            for direction in Direction::ORTHOGONAL {
+                let distances = part2::viewing_distances(&forest, direction);
+
+                for tree in forest.positions() {
+                    assert_eq!(
+                        distances[tree],
+                        part2::viewing_distance(&forest, tree, direction)
+                    );
+                }
+            }
+        }
+    }
+}
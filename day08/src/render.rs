@@ -0,0 +1,110 @@
+use grid::{Grid, Position};
+
+use crate::{Forest, Visibility};
+
+/// Characters for increasing scenic scores, from none at all to the best.
+const SHADES: &[u8] = b" .:-=+*#%@";
+
+/// How bright `value` is on a scale where `max` is 255.
+fn brightness(value: usize, max: usize) -> u8 {
+    match max {
+        0 => 0,
+        max => (value as u128 * 255 / max as u128) as u8,
+    }
+}
+
+/// Scenic scores as characters from [`SHADES`], relative to the highest
+/// score in the forest. Display the result to draw it in the terminal.
+pub fn heatmap(scores: &Grid<usize>) -> Grid<char> {
+    let max = scores.iter().map(|(_, &score)| score).max().unwrap_or(0);
+
+    scores.map(|&score| {
+        let shade = brightness(score, max) as usize * (SHADES.len() - 1) / 255;
+        SHADES[shade] as char
+    })
+}
+
+/// Scenic scores as a binary PGM image, one pixel per tree, with the highest
+/// score in white.
+pub fn pgm(scores: &Grid<usize>) -> Vec<u8> {
+    let max = scores.iter().map(|(_, &score)| score).max().unwrap_or(0);
+
+    let mut image = format!("P5\n{} {}\n255\n", scores.width(), scores.height()).into_bytes();
+    image.extend(scores.iter().map(|(_, &score)| brightness(score, max)));
+
+    image
+}
+
+/// The forest as a binary PPM image, one pixel per tree. Visible trees are
+/// green and hidden ones grey, both brighter the taller they are, and the
+/// tree at `highlight` is red.
+pub fn ppm(forest: &Forest, visibility: &Grid<Visibility>, highlight: Option<Position>) -> Vec<u8> {
+    let mut image = format!("P6\n{} {}\n255\n", forest.width(), forest.height()).into_bytes();
+
+    for (tree, &height) in forest.iter() {
+        let shade = 80 + (height as usize * 175 / 9) as u8;
+
+        let pixel = match (Some(tree) == highlight, visibility[tree].is_visible()) {
+            (true, _) => [255, 0, 0],
+            (false, true) => [0, shade, 0],
+            (false, false) => [shade / 2, shade / 2, shade / 2],
+        };
+
+        image.extend(pixel);
+    }
+
+    image
+}
+
+#[cfg(test)]
+mod tests {
+    use grid::{Grid, Position};
+
+    use crate::{
+        input_to_forest, part1, part2,
+        render::{heatmap, pgm, ppm},
+    };
+
+    const FOREST: &str = "30373\n25512\n65332\n33549\n35390";
+
+    #[test]
+    fn visibility_is_drawn_per_tree() {
+        let forest = input_to_forest(FOREST).unwrap();
+
+        assert_eq!(
+            part1::visibility(&forest).to_string(),
+            "21132\n122.1\n41.11\n1.2.4\n22142"
+        );
+    }
+
+    #[test]
+    fn heatmaps_scale_to_the_best_score() {
+        let forest = input_to_forest(FOREST).unwrap();
+        let scores = part2::scenic_scores(&forest);
+
+        assert_eq!(
+            heatmap(&scores).to_string(),
+            "     \n .=. \n *.: \n .@- \n     "
+        );
+        assert_eq!(heatmap(&Grid::new(2, 1, 0)).to_string(), "  ");
+    }
+
+    #[test]
+    fn images_have_one_pixel_per_tree() {
+        let forest = input_to_forest("123\n456").unwrap();
+        let scores = Grid::from_cells(3, vec![0, 1, 0, 0, 2, 0]).unwrap();
+
+        assert_eq!(pgm(&scores), b"P5\n3 2\n255\n\0\x7f\0\0\xff\0");
+
+        let image = ppm(
+            &forest,
+            &part1::visibility(&forest),
+            Some(Position::new(1, 1)),
+        );
+        let pixels = &image[b"P6\n3 2\n255\n".len()..];
+
+        assert!(image.starts_with(b"P6\n3 2\n255\n"));
+        assert_eq!(pixels.len(), 3 * 6);
+        assert_eq!(pixels[3 * 4..3 * 5], [255, 0, 0]);
+    }
+}
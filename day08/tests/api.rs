@@ -0,0 +1,75 @@
+use day08::{
+    generate_forest, input_to_forest, part1,
+    part2::{best_treehouses, viewing_distance},
+};
+use grid::{Direction, Position};
+
+#[test]
+fn forest_is_indexed_by_position() {
+    let forest = input_to_forest("123\n456\n789").unwrap();
+
+    assert_eq!((forest.width(), forest.height()), (3, 3));
+    assert_eq!(forest[Position::new(2, 0)], 3);
+    assert_eq!(forest[Position::new(0, 2)], 7);
+}
+
+#[test]
+fn trees_know_which_edges_they_are_visible_from() {
+    let forest = input_to_forest("30373\n25512\n65332\n33549\n35390").unwrap();
+    let tree = part1::visibility(&forest)[Position::new(1, 1)];
+
+    assert!(tree.from_edge(Direction::Up));
+    assert!(tree.from_edge(Direction::Left));
+    assert!(!tree.from_edge(Direction::Right));
+    assert!(!tree.from_edge(Direction::Down));
+}
+
+#[test]
+fn viewing_distance_stops_at_the_first_tall_tree() {
+    let forest = input_to_forest("30373\n25512\n65332\n33549\n35390").unwrap();
+    let tree = Position::new(2, 3);
+
+    assert_eq!(viewing_distance(&forest, tree, Direction::Up), 2);
+    assert_eq!(viewing_distance(&forest, tree, Direction::Left), 2);
+    assert_eq!(viewing_distance(&forest, tree, Direction::Down), 1);
+    assert_eq!(viewing_distance(&forest, tree, Direction::Right), 2);
+}
+
+#[test]
+fn empty_forests_have_no_trees() {
+    let forest = input_to_forest("").unwrap();
+
+    assert_eq!((forest.width(), forest.height()), (0, 0));
+    assert_eq!(day08::part1::solve(&forest), 0);
+    assert_eq!(day08::part2::solve(&forest), 0);
+
+    let forest = generate_forest(5, 0, 1);
+
+    assert_eq!((forest.width(), forest.height()), (5, 0));
+    assert_eq!(day08::part1::solve(&forest), 0);
+    assert_eq!(day08::part2::solve(&forest), 0);
+}
+
+#[test]
+fn best_treehouses_come_with_their_view() {
+    let forest = input_to_forest("30373\n25512\n65332\n33549\n35390").unwrap();
+    let best = best_treehouses(&forest, 8);
+
+    assert_eq!(best[0].position, Position::new(2, 3));
+    assert_eq!(best[0].distances, [2, 1, 2, 2]);
+    assert_eq!(best[0].distance(Direction::Left), 2);
+    assert_eq!(best[0].score, 8);
+
+    let scores = best
+        .iter()
+        .map(|treehouse| treehouse.score)
+        .collect::<Vec<_>>();
+    assert_eq!(scores, [8, 6, 4, 3, 2, 1, 1, 1]);
+
+    // Trees scoring 1 are ties, so they come in reading order
+    let ties = best[5..].iter().map(|treehouse| treehouse.position);
+    assert!(ties.eq([(1, 1), (3, 1), (2, 2)].map(|(x, y)| Position::new(x, y))));
+
+    assert_eq!(best_treehouses(&forest, 100).len(), 25);
+    assert!(best_treehouses(&input_to_forest("").unwrap(), 1).is_empty());
+}
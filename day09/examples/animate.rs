@@ -0,0 +1,94 @@
+//! Draws the rope after every motion, or after every step in a range of
+//! steps, and optionally writes the trail of every knot to a file.
+//!
+//!     cargo run -p day09 --example animate -- --knots 10
+//!     cargo run -p day09 --example animate -- --steps 5..12 --svg trails.svg
+//!     cargo run -p day09 --example animate -- --csv trails.csv
+
+use std::{env, fs, ops::Range, process::exit};
+
+use common::{input, Solution};
+use day09::{render, Day09};
+
+const USAGE: &str = "Usage: animate [--knots N] [--steps FROM..TO] [--csv <PATH>] [--svg <PATH>]";
+
+fn usage() -> ! {
+    eprintln!("{USAGE}");
+    exit(2);
+}
+
+fn parse_range(range: &str) -> Option<Range<usize>> {
+    let (from, to) = range.split_once("..")?;
+
+    Some(from.parse().ok()?..to.parse().ok()?)
+}
+
+fn write(path: &str, contents: &str) {
+    if let Err(error) = fs::write(path, contents) {
+        eprintln!("Could not write {path}: {error}");
+        exit(1);
+    }
+}
+
+fn main() {
+    let mut knots = 10;
+    let mut steps = None;
+    let (mut csv, mut svg) = (None, None);
+    let mut args = env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        let value = args.next().unwrap_or_else(|| usage());
+
+        match arg.as_str() {
+            "--knots" => match value.parse() {
+                Ok(count) if count > 0 => knots = count,
+                _ => usage(),
+            },
+            "--steps" => steps = Some(parse_range(&value).unwrap_or_else(|| usage())),
+            "--csv" => csv = Some(value),
+            "--svg" => svg = Some(value),
+            _ => usage(),
+        }
+    }
+
+    let input = match input::load(Day09::DAY, None) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{error}");
+            exit(1);
+        }
+    };
+
+    let motions = match Day09::parse(&input) {
+        Ok(motions) => motions,
+        Err(error) => {
+            eprintln!("Could not parse input: {error}");
+            exit(1);
+        }
+    };
+
+    let all_steps = render::steps::<2>(&motions, knots);
+
+    let (frames, first, name) = match steps {
+        Some(range) => {
+            let range = range.start.min(all_steps.len())..range.end.min(all_steps.len());
+            (all_steps[range.clone()].to_vec(), range.start, "Step")
+        }
+        None => (render::moves::<2>(&motions, knots), 0, "Move"),
+    };
+
+    let bounds = render::bounds(&frames);
+
+    for (index, frame) in frames.iter().enumerate() {
+        println!("== {name} {} ==", first + index);
+        println!("{}", render::draw(frame, bounds));
+    }
+
+    if let Some(path) = csv {
+        write(&path, &render::trails_csv(&all_steps));
+    }
+
+    if let Some(path) = svg {
+        write(&path, &render::trails_svg(&all_steps));
+    }
+}
@@ -0,0 +1,76 @@
+//! Pulls a rope through motions as they are read, without holding the whole
+//! move list in memory, and prints how many cells its tail visited.
+//!
+//!     generate-moves | cargo run --release -p day09 --example stream -- --knots 10
+//!     cargo run -p day09 --example stream -- --dimensions 3 moves.txt
+
+use std::{
+    env,
+    fs::File,
+    io::{self, BufRead, BufReader},
+    process::exit,
+};
+
+use day09::{Motions, Rope, Trail};
+
+const USAGE: &str = "Usage: stream [--knots N] [--dimensions 2|3] [PATH]";
+
+fn usage() -> ! {
+    eprintln!("{USAGE}");
+    exit(2);
+}
+
+fn simulate<const D: usize>(reader: impl BufRead, knots: usize) -> usize {
+    let mut rope = Rope::<D>::with_knots(knots);
+
+    for motion in Motions::new(reader).dimensions(D) {
+        match motion {
+            Ok(motion) => rope.pull(motion),
+            Err(error) => {
+                eprintln!("Could not parse input: {error}");
+                exit(1);
+            }
+        }
+    }
+
+    rope.history(knots - 1).map_or(0, Trail::cells)
+}
+
+fn main() {
+    let (mut knots, mut dimensions, mut path) = (10, 2, None);
+    let mut args = env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--knots" => match args.next().and_then(|knots| knots.parse().ok()) {
+                Some(count) if count > 0 => knots = count,
+                _ => usage(),
+            },
+            "--dimensions" => match args.next().as_deref() {
+                Some("2") => dimensions = 2,
+                Some("3") => dimensions = 3,
+                _ => usage(),
+            },
+            _ if path.is_none() => path = Some(arg),
+            _ => usage(),
+        }
+    }
+
+    let reader: Box<dyn BufRead> = match path {
+        Some(path) => match File::open(&path) {
+            Ok(file) => Box::new(BufReader::new(file)),
+            Err(error) => {
+                eprintln!("Could not open {path}: {error}");
+                exit(1);
+            }
+        },
+        None => Box::new(io::stdin().lock()),
+    };
+
+    let cells = match dimensions {
+        3 => simulate::<3>(reader, knots),
+        _ => simulate::<2>(reader, knots),
+    };
+
+    println!("The tail of the rope visited {cells} cells");
+}
@@ -0,0 +1,413 @@
+use std::collections::HashMap;
+
+use common::{ParseError, Solution};
+
+mod motions;
+pub mod render;
+
+pub use motions::{parse_motion, Motions};
+
+/// A direction to move the head in. Right, up and forward are the positive
+/// x, y and z axes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+    Forward,
+    Backward,
+}
+
+impl Direction {
+    /// How many axes a rope needs to move this way.
+    pub fn dimensions(&self) -> usize {
+        match self {
+            Direction::Forward | Direction::Backward => 3,
+            _ => 2,
+        }
+    }
+
+    /// The step taken along each axis of a `D` dimensional rope, or `None` if
+    /// it needs an axis the rope does not have.
+    pub fn offset<const D: usize>(&self) -> Option<[i32; D]> {
+        let (x, y, z) = match self {
+            Direction::Up => (0, 1, 0),
+            Direction::Down => (0, -1, 0),
+            Direction::Left => (-1, 0, 0),
+            Direction::Right => (1, 0, 0),
+            Direction::UpLeft => (-1, 1, 0),
+            Direction::UpRight => (1, 1, 0),
+            Direction::DownLeft => (-1, -1, 0),
+            Direction::DownRight => (1, -1, 0),
+            Direction::Forward => (0, 0, 1),
+            Direction::Backward => (0, 0, -1),
+        };
+
+        let axes = [x, y, z];
+
+        match axes[D.min(3)..].iter().all(|&step| step == 0) {
+            true => Some(std::array::from_fn(|axis| {
+                axes.get(axis).copied().unwrap_or(0)
+            })),
+            false => None,
+        }
+    }
+}
+
+pub type Motion = (Direction, usize);
+
+/// The position of a knot in `D` dimensions, with the axes in the order x,
+/// y, z.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Knot<const D: usize = 2>(pub [i32; D]);
+
+impl Knot {
+    pub fn new(x: i32, y: i32) -> Self {
+        Self([x, y])
+    }
+
+    pub fn x(&self) -> i32 {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> i32 {
+        self.0[1]
+    }
+}
+
+impl<const D: usize> Default for Knot<D> {
+    fn default() -> Self {
+        Self([0; D])
+    }
+}
+
+impl<const D: usize> Knot<D> {
+    /// Whether the knots overlap or are next to each other, diagonals
+    /// included.
+    pub fn touches(&self, other: Knot<D>) -> bool {
+        self.0.iter().zip(other.0).all(|(a, b)| (a - b).abs() <= 1)
+    }
+
+    /// Moves one step towards `leader` along each axis it is off by, unless
+    /// the two already touch. Returns whether the knot moved.
+    pub fn follow(&mut self, leader: Knot<D>) -> bool {
+        if self.touches(leader) {
+            return false;
+        }
+
+        for (axis, target) in self.0.iter_mut().zip(leader.0) {
+            *axis += (target - *axis).signum();
+        }
+
+        true
+    }
+}
+
+/// The smallest box holding a set of knot positions, edges included.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Bounds<const D: usize = 2> {
+    pub min: Knot<D>,
+    pub max: Knot<D>,
+}
+
+impl<const D: usize> Bounds<D> {
+    /// The bounds of `knots`, or `None` if there are none.
+    pub fn around(knots: impl IntoIterator<Item = Knot<D>>) -> Option<Self> {
+        knots.into_iter().fold(None, |bounds, knot| {
+            Some(match bounds {
+                None => Bounds {
+                    min: knot,
+                    max: knot,
+                },
+                Some(Bounds { min, max }) => Bounds {
+                    min: Knot(std::array::from_fn(|axis| min.0[axis].min(knot.0[axis]))),
+                    max: Knot(std::array::from_fn(|axis| max.0[axis].max(knot.0[axis]))),
+                },
+            })
+        })
+    }
+
+    /// How many cells the bounds span along each axis.
+    pub fn lengths(&self) -> [usize; D] {
+        std::array::from_fn(|axis| self.min.0[axis].abs_diff(self.max.0[axis]) as usize + 1)
+    }
+}
+
+impl Bounds {
+    pub fn width(&self) -> usize {
+        self.lengths()[0]
+    }
+
+    pub fn height(&self) -> usize {
+        self.lengths()[1]
+    }
+}
+
+/// Where a tracked knot has been, and how often it came to each cell. The
+/// cell it was in when tracking started counts as one visit, and after that
+/// every step that moves the knot into a cell adds one.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Trail<const D: usize = 2> {
+    visits: HashMap<Knot<D>, usize>,
+}
+
+impl<const D: usize> Trail<D> {
+    fn visit(&mut self, knot: Knot<D>) {
+        *self.visits.entry(knot).or_default() += 1;
+    }
+
+    /// The number of distinct cells visited.
+    pub fn cells(&self) -> usize {
+        self.visits.len()
+    }
+
+    pub fn contains(&self, knot: Knot<D>) -> bool {
+        self.visits.contains_key(&knot)
+    }
+
+    /// How many times the knot came to `knot`'s cell.
+    pub fn visits(&self, knot: Knot<D>) -> usize {
+        self.visits.get(&knot).copied().unwrap_or(0)
+    }
+
+    /// The total number of visits over all cells.
+    pub fn total_visits(&self) -> usize {
+        self.visits.values().sum()
+    }
+
+    /// The box around every visited cell, or `None` before any visit.
+    pub fn bounds(&self) -> Option<Bounds<D>> {
+        Bounds::around(self.visits.keys().copied())
+    }
+
+    /// Every visited cell with its number of visits, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Knot<D>, usize)> + '_ {
+        self.visits.iter().map(|(&knot, &visits)| (knot, visits))
+    }
+}
+
+/// A rope of knots in `D` dimensions all starting at the origin, where the
+/// first knot is the head and the last the tail.
+#[derive(Clone, Debug)]
+pub struct Rope<const D: usize = 2> {
+    knots: Vec<Knot<D>>,
+    /// Every position the knot at the same index has been in, for the knots
+    /// that are being tracked.
+    trails: Vec<Option<Trail<D>>>,
+}
+
+impl Rope {
+    /// A flat rope of `knots` knots, tracking where its tail goes.
+    pub fn new(knots: usize) -> Self {
+        Self::with_knots(knots)
+    }
+}
+
+impl<const D: usize> Rope<D> {
+    /// A rope of `knots` knots in `D` dimensions, tracking where its tail
+    /// goes, as in `Rope::<3>::with_knots(10)`.
+    pub fn with_knots(knots: usize) -> Self {
+        assert!(knots > 0, "A rope needs at least one knot");
+
+        let mut rope = Self {
+            knots: vec![Knot::default(); knots],
+            trails: vec![None; knots],
+        };
+
+        rope.track(knots - 1);
+
+        rope
+    }
+
+    /// Starts recording the positions of the knot at `index`, beginning
+    /// with the one it is in now. A knot that is already tracked keeps the
+    /// trail it has.
+    pub fn track(&mut self, index: usize) {
+        if self.trails[index].is_some() {
+            return;
+        }
+
+        let mut trail = Trail::default();
+        trail.visit(self.knots[index]);
+
+        self.trails[index] = Some(trail);
+    }
+
+    /// Stops recording the positions of the knot at `index`, forgetting
+    /// where it has been so far.
+    pub fn untrack(&mut self, index: usize) {
+        self.trails[index] = None;
+    }
+
+    /// Every position the knot at `index` has been in since it was tracked,
+    /// or `None` if it is not.
+    pub fn history(&self, index: usize) -> Option<&Trail<D>> {
+        self.trails.get(index)?.as_ref()
+    }
+
+    /// Starts recording the positions of every knot.
+    pub fn track_all(&mut self) {
+        for index in 0..self.knots.len() {
+            self.track(index);
+        }
+    }
+
+    /// The trail of every tracked knot, by index from the head.
+    pub fn trails(&self) -> impl Iterator<Item = (usize, &Trail<D>)> {
+        self.trails
+            .iter()
+            .enumerate()
+            .filter_map(|(index, trail)| Some((index, trail.as_ref()?)))
+    }
+
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.knots.len()
+    }
+
+    pub fn knots(&self) -> &[Knot<D>] {
+        &self.knots
+    }
+
+    pub fn head(&self) -> Knot<D> {
+        self.knots[0]
+    }
+
+    pub fn tail(&self) -> Knot<D> {
+        self.knots[self.knots.len() - 1]
+    }
+
+    /// Moves the head `steps` times in `direction`.
+    pub fn pull(&mut self, (direction, steps): Motion) {
+        for _ in 0..steps {
+            self.step(direction);
+        }
+    }
+
+    /// Moves the head one step and lets every following knot catch up.
+    ///
+    /// Panics if `direction` needs more dimensions than the rope has.
+    pub fn step(&mut self, direction: Direction) {
+        let Some(offset) = direction.offset::<D>() else {
+            panic!("A rope in {D} dimensions cannot move {direction:?}");
+        };
+
+        for (axis, step) in self.knots[0].0.iter_mut().zip(offset) {
+            *axis += step;
+        }
+
+        self.record(0);
+
+        for index in 1..self.knots.len() {
+            let leader = self.knots[index - 1];
+
+            // A knot that stays put leaves the rest of the rope as it was
+            if !self.knots[index].follow(leader) {
+                break;
+            }
+
+            self.record(index);
+        }
+    }
+
+    fn record(&mut self, index: usize) {
+        if let Some(trail) = &mut self.trails[index] {
+            trail.visit(self.knots[index]);
+        }
+    }
+}
+
+/// Pulls a rope of `knots` knots in `D` dimensions through every motion, and
+/// returns how many positions its tail visited.
+pub fn simulate<const D: usize>(motions: &[Motion], knots: usize) -> usize {
+    let mut rope = Rope::<D>::with_knots(knots);
+
+    for &motion in motions {
+        rope.pull(motion);
+    }
+
+    rope.history(knots - 1).map_or(0, Trail::cells)
+}
+
+pub mod part1 {
+    use crate::{simulate, Motion};
+
+    pub fn solve(motions: &[Motion]) -> usize {
+        simulate::<2>(motions, 2)
+    }
+}
+
+pub mod part2 {
+    use crate::{simulate, Motion};
+
+    pub fn solve(motions: &[Motion]) -> usize {
+        simulate::<2>(motions, 10)
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Motion>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // The puzzle's rope lies flat
+        Motions::new(input.as_bytes()).dimensions(2).collect()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day09;
+    use common::Solution;
+    const TEST_INPUT: &str = "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2";
+    const TEST_INPUT_2: &str = "R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20";
+    const TEST_INPUT_3: &str = "R 5\nU 8";
+
+    #[test]
+    fn validate_part1() {
+        assert_eq!(Day09::part1(&Day09::parse(TEST_INPUT).unwrap()), 13);
+    }
+
+    #[test]
+    fn validate_part2() {
+        assert_eq!(Day09::part2(&Day09::parse(TEST_INPUT).unwrap()), 1);
+        assert_eq!(Day09::part2(&Day09::parse(TEST_INPUT_2).unwrap()), 36);
+        // As in the puzzle text, the head is still too close to pull the tail
+        assert_eq!(Day09::part2(&Day09::parse(TEST_INPUT_3).unwrap()), 1);
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_offending_token() {
+        let error = Day09::parse("R 4\nX 4").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 1));
+
+        let error = Day09::parse("R 4\nU four").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.text, "four");
+
+        let error = Day09::parse("UR 2\nF 1").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.reason, "a rope in 2 dimensions cannot move this way");
+    }
+}
@@ -0,0 +1,183 @@
+use std::io::BufRead;
+
+use common::ParseError;
+
+use crate::{Direction, Motion};
+
+/// Parses the line at 0-based `index` into a direction and a step count. All
+/// directions are accepted, whether or not a rope has the axes to take them.
+pub fn parse_motion(index: usize, line: &str) -> Result<Motion, ParseError> {
+    let tokens: Vec<&str> = line.split_ascii_whitespace().collect();
+
+    if tokens.len() != 2 {
+        return Err(ParseError::line(
+            index,
+            line,
+            "expected a direction and a step count",
+        ));
+    }
+
+    let direction = match tokens[0] {
+        "U" => Direction::Up,
+        "D" => Direction::Down,
+        "L" => Direction::Left,
+        "R" => Direction::Right,
+        "UL" => Direction::UpLeft,
+        "UR" => Direction::UpRight,
+        "DL" => Direction::DownLeft,
+        "DR" => Direction::DownRight,
+        "F" => Direction::Forward,
+        "B" => Direction::Backward,
+        _ => {
+            return Err(ParseError::new(
+                index,
+                line,
+                tokens[0],
+                "unknown movement direction",
+            ))
+        }
+    };
+
+    match tokens[1].parse::<usize>() {
+        Ok(steps) => Ok((direction, steps)),
+        Err(_) => Err(ParseError::new(
+            index,
+            line,
+            tokens[1],
+            "expected a step count",
+        )),
+    }
+}
+
+/// Reads motions one line at a time from any reader, so that move lists too
+/// big to hold in memory can be simulated as they are read. Stops after the
+/// first error, which points at the offending line and token; a line that
+/// cannot be read at all is reported as an error on that line.
+pub struct Motions<R> {
+    reader: R,
+    index: usize,
+    line: String,
+    dimensions: Option<usize>,
+    failed: bool,
+}
+
+impl<R: BufRead> Motions<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            index: 0,
+            line: String::new(),
+            dimensions: None,
+            failed: false,
+        }
+    }
+
+    /// Rejects directions that need more than `dimensions` axes, instead of
+    /// accepting every direction.
+    pub fn dimensions(mut self, dimensions: usize) -> Self {
+        self.dimensions = Some(dimensions);
+        self
+    }
+
+    fn read(&mut self) -> Option<Result<Motion, ParseError>> {
+        self.line.clear();
+
+        match self.reader.read_line(&mut self.line) {
+            Ok(0) => return None,
+            Ok(_) => {}
+            Err(error) => {
+                let reason = format!("could not read the line: {error}");
+                return Some(Err(ParseError::line(self.index, "", reason)));
+            }
+        }
+
+        let line = self.line.trim_end_matches(['\n', '\r']);
+        let motion = parse_motion(self.index, line);
+
+        Some(match (motion, self.dimensions) {
+            (Ok((direction, _)), Some(dimensions)) if direction.dimensions() > dimensions => {
+                Err(ParseError::new(
+                    self.index,
+                    line,
+                    line.split_ascii_whitespace().next().unwrap_or(line),
+                    format!("a rope in {dimensions} dimensions cannot move this way"),
+                ))
+            }
+            (motion, _) => motion,
+        })
+    }
+}
+
+impl<R: BufRead> Iterator for Motions<R> {
+    type Item = Result<Motion, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        let motion = self.read();
+
+        self.index += 1;
+        self.failed = matches!(motion, Some(Err(_)));
+
+        motion
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, BufRead, BufReader, Read};
+
+    use crate::{Direction, Motions};
+
+    #[test]
+    fn motions_are_read_line_by_line() {
+        let motions = Motions::new("R 4\r\nUL 2\nF 1\n".as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(
+            motions,
+            [
+                (Direction::Right, 4),
+                (Direction::UpLeft, 2),
+                (Direction::Forward, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn reading_stops_at_the_first_error() {
+        let mut motions = Motions::new("R 4\nX 1\nR 2\n".as_bytes());
+
+        assert!(motions.next().unwrap().is_ok());
+
+        let error = motions.next().unwrap().unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.text, "X");
+        assert!(motions.next().is_none());
+    }
+
+    #[test]
+    fn unreadable_lines_are_errors_too() {
+        struct Broken;
+
+        impl Read for Broken {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("disk on fire"))
+            }
+        }
+
+        let reader: Box<dyn BufRead> =
+            Box::new(io::Cursor::new("R 1\n").chain(BufReader::new(Broken)));
+        let errors = Motions::new(reader)
+            .filter_map(Result::err)
+            .collect::<Vec<_>>();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line, 2);
+        assert_eq!(errors[0].reason, "could not read the line: disk on fire");
+    }
+}
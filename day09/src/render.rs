@@ -0,0 +1,241 @@
+use std::fmt::Write;
+
+use crate::{Bounds, Knot, Motion, Rope};
+
+/// Where every knot of a rope is at one point of a simulation, seen from
+/// above: only the x and y axes are kept.
+pub type Frame = Vec<Knot>;
+
+fn project<const D: usize>(rope: &Rope<D>) -> Frame {
+    let axis = |knot: &Knot<D>, axis: usize| knot.0.get(axis).copied().unwrap_or(0);
+
+    rope.knots()
+        .iter()
+        .map(|knot| Knot::new(axis(knot, 0), axis(knot, 1)))
+        .collect()
+}
+
+/// The rope at the start and after every single step of `motions`, pulling
+/// a rope in `D` dimensions and projecting it onto the x and y axes. Like
+/// [`Rope::step`], this panics on a motion that needs more than `D` axes,
+/// so `D` should be at least what the motions were parsed for, as with
+/// [`Motions::dimensions`](crate::Motions::dimensions).
+pub fn steps<const D: usize>(motions: &[Motion], knots: usize) -> Vec<Frame> {
+    let mut rope = Rope::<D>::with_knots(knots);
+    let mut frames = vec![project(&rope)];
+
+    for &(direction, steps) in motions {
+        for _ in 0..steps {
+            rope.step(direction);
+            frames.push(project(&rope));
+        }
+    }
+
+    frames
+}
+
+/// The rope at the start and after every whole motion, in `D` dimensions
+/// projected onto the x and y axes like [`steps`].
+pub fn moves<const D: usize>(motions: &[Motion], knots: usize) -> Vec<Frame> {
+    let mut rope = Rope::<D>::with_knots(knots);
+    let mut frames = vec![project(&rope)];
+
+    for &motion in motions {
+        rope.pull(motion);
+        frames.push(project(&rope));
+    }
+
+    frames
+}
+
+/// The bounds of every knot in every frame, and of the starting point, so
+/// that frames drawn with them line up.
+pub fn bounds(frames: &[Frame]) -> Bounds {
+    let knots = frames.iter().flatten().copied();
+
+    Bounds::around(knots.chain([Knot::default()])).unwrap()
+}
+
+/// How the knot at `index` is drawn, following the puzzle up to the tenth.
+pub fn label(index: usize) -> char {
+    match index {
+        0 => 'H',
+        1..=9 => char::from_digit(index as u32, 10).unwrap(),
+        10..=35 => (b'a' + (index - 10) as u8) as char,
+        _ => '#',
+    }
+}
+
+/// Draws `frame` on a canvas the size of `bounds`, with y growing upwards
+/// like in the puzzle. Knots closer to the head are drawn over those behind
+/// them, and the starting point is an `s` if no knot covers it. Knots
+/// outside `bounds` are left out.
+pub fn draw(frame: &[Knot], bounds: Bounds) -> String {
+    let mut canvas = vec![vec!['.'; bounds.width()]; bounds.height()];
+    // How far `to` is past `from`, or nothing if it is before it
+    let offset = |from: i32, to: i32| usize::try_from(to.checked_sub(from)?).ok();
+    let mut put = |knot: Knot, c| {
+        let column = offset(bounds.min.x(), knot.x());
+        let row = offset(knot.y(), bounds.max.y());
+
+        if let Some(cell) = column
+            .zip(row)
+            .and_then(|(column, row)| canvas.get_mut(row)?.get_mut(column))
+        {
+            *cell = c;
+        }
+    };
+
+    put(Knot::default(), 's');
+
+    for (index, &knot) in frame.iter().enumerate().rev() {
+        put(knot, label(index));
+    }
+
+    let mut drawing = String::new();
+
+    for row in canvas {
+        drawing.extend(row);
+        drawing.push('\n');
+    }
+
+    drawing
+}
+
+/// Every position of every knot, one row per knot per frame.
+pub fn trails_csv(frames: &[Frame]) -> String {
+    let mut csv = "frame,knot,x,y\n".to_string();
+
+    for (index, frame) in frames.iter().enumerate() {
+        for (knot, position) in frame.iter().enumerate() {
+            writeln!(csv, "{index},{knot},{},{}", position.x(), position.y()).unwrap();
+        }
+    }
+
+    csv
+}
+
+/// The trail of every knot as a polyline, the head in red and the others
+/// fading towards the tail, one unit per cell. SVG has y growing downwards,
+/// so it is flipped to match [`draw`].
+pub fn trails_svg(frames: &[Frame]) -> String {
+    let bounds = bounds(frames);
+    let knots = frames.first().map_or(0, Vec::len);
+
+    let mut svg = String::new();
+    writeln!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\">",
+        bounds.min.x() - 1,
+        -bounds.max.y() - 1,
+        bounds.width() + 1,
+        bounds.height() + 1
+    )
+    .unwrap();
+
+    for knot in (0..knots).rev() {
+        let points = frames
+            .iter()
+            .map(|frame| format!("{},{}", frame[knot].x(), -frame[knot].y()))
+            .collect::<Vec<_>>();
+        let colour = match knot {
+            0 => "red".to_string(),
+            _ => format!("hsl(220, 60%, {}%)", 30 + 50 * knot / knots),
+        };
+
+        writeln!(
+            svg,
+            "  <polyline fill=\"none\" stroke=\"{colour}\" stroke-width=\"0.2\" points=\"{}\"/>",
+            points.join(" ")
+        )
+        .unwrap();
+    }
+
+    svg.push_str("</svg>\n");
+
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use crate::{
+        render::{bounds, draw, moves, steps, trails_csv, trails_svg},
+        Bounds, Day09, Knot, Motions,
+    };
+
+    #[test]
+    fn frames_are_drawn_like_in_the_puzzle() {
+        let motions = Day09::parse("R 4\nU 4").unwrap();
+        let frames = moves::<2>(&motions, 10);
+        let bounds = bounds(&frames);
+
+        assert_eq!(frames.len(), 3);
+        assert_eq!(
+            draw(&frames[0], bounds),
+            ".....\n.....\n.....\n.....\nH....\n"
+        );
+        assert_eq!(
+            draw(&frames[1], bounds),
+            ".....\n.....\n.....\n.....\n4321H\n"
+        );
+        assert_eq!(
+            draw(&frames[2], bounds),
+            "....H\n....1\n..432\n.5...\n6....\n"
+        );
+    }
+
+    #[test]
+    fn the_start_shows_when_nothing_covers_it() {
+        let frame = [Knot::new(2, 1), Knot::new(1, 1)];
+        let bounds = Bounds::around([Knot::default(), Knot::new(2, 1)]).unwrap();
+
+        assert_eq!(draw(&frame, bounds), ".1H\ns..\n");
+    }
+
+    #[test]
+    fn knots_outside_the_bounds_are_left_out() {
+        let bounds = Bounds::around([Knot::new(0, 0), Knot::new(2, 1)]).unwrap();
+        let frame = [
+            Knot::new(-1, 0),
+            Knot::new(1, 2),
+            Knot::new(3, 1),
+            Knot::new(1, -1),
+            Knot::new(1, 1),
+        ];
+
+        assert_eq!(draw(&frame, bounds), ".4.\ns..\n");
+    }
+
+    #[test]
+    fn trails_hold_every_frame() {
+        let motions = Day09::parse("R 2").unwrap();
+        let frames = steps::<2>(&motions, 2);
+
+        assert_eq!(
+            trails_csv(&frames),
+            "frame,knot,x,y\n0,0,0,0\n0,1,0,0\n1,0,1,0\n1,1,0,0\n2,0,2,0\n2,1,1,0\n"
+        );
+
+        let svg = trails_svg(&frames);
+
+        assert!(svg.contains("stroke=\"red\" stroke-width=\"0.2\" points=\"0,0 1,0 2,0\""));
+        assert!(svg.contains("points=\"0,0 0,0 1,0\""));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn ropes_in_space_are_drawn_from_above() {
+        let motions = Motions::new("F 3\nR 1\n".as_bytes())
+            .dimensions(3)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let frames = moves::<3>(&motions, 2);
+
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[1], [Knot::new(0, 0), Knot::new(0, 0)]);
+        assert_eq!(frames[2], [Knot::new(1, 0), Knot::new(0, 0)]);
+        assert_eq!(steps::<3>(&motions, 2).len(), 5);
+    }
+}
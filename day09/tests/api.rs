@@ -0,0 +1,143 @@
+use day09::{parse_motion, Bounds, Direction, Knot, Rope};
+
+#[test]
+fn parse_motion_reads_direction_and_steps() {
+    let (direction, steps) = parse_motion(0, "L 12").unwrap();
+
+    assert!(matches!(direction, Direction::Left));
+    assert_eq!(steps, 12);
+}
+
+#[test]
+fn tail_trails_behind_the_head() {
+    let mut rope = Rope::new(2);
+
+    for _ in 0..3 {
+        rope.step(Direction::Right);
+    }
+
+    assert_eq!(rope.head(), Knot::new(3, 0));
+    assert_eq!(rope.tail(), Knot::new(2, 0));
+    assert_eq!(rope.history(1).unwrap().cells(), 3);
+    assert!(rope.history(0).is_none());
+}
+
+#[test]
+fn ropes_have_as_many_knots_as_asked_for() {
+    assert_eq!(Rope::new(10).len(), 10);
+    assert_eq!(Rope::new(10).knots(), [Knot::default(); 10]);
+}
+
+#[test]
+fn any_knot_can_be_tracked() {
+    let mut rope = Rope::new(3);
+
+    rope.track(0);
+    rope.track(1);
+    rope.untrack(2);
+
+    for direction in [Direction::Up, Direction::Up, Direction::Right] {
+        rope.step(direction);
+    }
+
+    assert_eq!(rope.history(0).unwrap().cells(), 4);
+    assert_eq!(rope.history(1).unwrap().cells(), 2);
+    assert!(rope.history(2).is_none());
+}
+
+#[test]
+fn followers_catch_up_diagonally() {
+    let mut knot = Knot::new(0, 0);
+
+    assert!(!knot.follow(Knot::new(1, 1)));
+    assert!(knot.follow(Knot::new(2, 1)));
+    assert_eq!(knot, Knot::new(1, 1));
+    assert!(knot.follow(Knot::new(3, 3)));
+    assert_eq!(knot, Knot::new(2, 2));
+}
+
+#[test]
+fn diagonal_moves_step_along_both_axes() {
+    let (direction, steps) = parse_motion(0, "UL 3").unwrap();
+    let mut rope = Rope::new(2);
+
+    for _ in 0..steps {
+        rope.step(direction);
+    }
+
+    assert_eq!(rope.head(), Knot::new(-3, 3));
+    assert_eq!(rope.tail(), Knot::new(-2, 2));
+}
+
+#[test]
+fn ropes_can_move_in_three_dimensions() {
+    let mut rope = Rope::<3>::with_knots(3);
+
+    for direction in [Direction::Forward, Direction::Forward, Direction::UpRight] {
+        rope.step(direction);
+    }
+
+    assert_eq!(rope.head(), Knot([1, 1, 2]));
+    assert_eq!(rope.knots()[1], Knot([0, 0, 1]));
+    assert_eq!(rope.tail(), Knot([0, 0, 0]));
+    assert_eq!(Direction::Forward.offset::<2>(), None);
+}
+
+#[test]
+#[should_panic(expected = "A rope in 2 dimensions cannot move Backward")]
+fn flat_ropes_cannot_move_backward() {
+    Rope::new(2).step(Direction::Backward);
+}
+
+#[test]
+fn trails_tell_how_much_ground_each_knot_covers() {
+    let mut rope = Rope::new(3);
+
+    rope.track_all();
+
+    for (direction, steps) in [(Direction::Right, 4), (Direction::Left, 4)] {
+        for _ in 0..steps {
+            rope.step(direction);
+        }
+    }
+
+    let cells = rope
+        .trails()
+        .map(|(index, trail)| (index, trail.cells()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(cells, [(0, 5), (1, 4), (2, 3)]);
+
+    let head = rope.history(0).unwrap();
+
+    assert_eq!(head.visits(Knot::new(0, 0)), 2);
+    assert_eq!(head.visits(Knot::new(4, 0)), 1);
+    assert_eq!(head.visits(Knot::new(0, 1)), 0);
+    assert_eq!(head.total_visits(), 9);
+    assert_eq!(
+        head.bounds(),
+        Some(Bounds {
+            min: Knot::new(0, 0),
+            max: Knot::new(4, 0),
+        })
+    );
+    assert_eq!(head.bounds().unwrap().width(), 5);
+    assert_eq!(rope.history(2).unwrap().bounds().unwrap().width(), 3);
+}
+
+#[test]
+fn tracking_a_tracked_knot_changes_nothing() {
+    let mut rope = Rope::new(2);
+
+    rope.track(0);
+    rope.step(Direction::Right);
+    rope.track(0);
+    rope.track(1);
+    rope.track_all();
+
+    let head = rope.history(0).unwrap();
+
+    assert_eq!(head.visits(Knot::new(1, 0)), 1);
+    assert_eq!(head.total_visits(), 2);
+    assert_eq!(rope.history(1).unwrap().total_visits(), 1);
+}
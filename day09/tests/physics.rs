@@ -0,0 +1,126 @@
+use common::Solution;
+use day09::{simulate, Day09, Direction, Knot, Rope};
+use proptest::prelude::*;
+
+fn direction() -> impl Strategy<Value = Direction> {
+    prop_oneof![
+        Just(Direction::Up),
+        Just(Direction::Down),
+        Just(Direction::Left),
+        Just(Direction::Right),
+        Just(Direction::UpLeft),
+        Just(Direction::UpRight),
+        Just(Direction::DownLeft),
+        Just(Direction::DownRight),
+    ]
+}
+
+fn direction_3d() -> impl Strategy<Value = Direction> {
+    prop_oneof![
+        direction(),
+        Just(Direction::Forward),
+        Just(Direction::Backward),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn adjacent_knots_touch_after_every_step(
+        knots in 1..16usize,
+        motions in prop::collection::vec((direction(), 1..12usize), 0..40),
+    ) {
+        let mut rope = Rope::new(knots);
+
+        for (direction, steps) in motions {
+            for _ in 0..steps {
+                let before = rope.knots().to_vec();
+
+                rope.step(direction);
+
+                for pair in rope.knots().windows(2) {
+                    prop_assert!(pair[0].touches(pair[1]), "{:?}", rope.knots());
+                }
+
+                // No knot ever moves more than one step along either axis
+                for (old, new) in before.iter().zip(rope.knots()) {
+                    prop_assert!(old.touches(*new));
+                }
+            }
+        }
+
+        prop_assert!(rope.history(knots - 1).unwrap().contains(rope.tail()));
+    }
+
+    #[test]
+    fn ropes_in_space_stay_together(
+        knots in 1..12usize,
+        motions in prop::collection::vec((direction_3d(), 1..8usize), 0..30),
+    ) {
+        let mut rope = Rope::<3>::with_knots(knots);
+
+        for (direction, steps) in motions {
+            for _ in 0..steps {
+                rope.step(direction);
+
+                for pair in rope.knots().windows(2) {
+                    prop_assert!(pair[0].touches(pair[1]), "{:?}", rope.knots());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn followers_move_one_step_closer_unless_touching(
+        x in -3..=3i32,
+        y in -3..=3i32,
+    ) {
+        let leader = Knot::new(x, y);
+        let mut knot = Knot::default();
+        let moved = knot.follow(leader);
+
+        let distance = |knot: Knot| (knot.x() - x).abs().max((knot.y() - y).abs());
+
+        prop_assert_eq!(moved, !Knot::default().touches(leader));
+
+        if moved {
+            prop_assert!(knot.touches(Knot::default()));
+            prop_assert_eq!(distance(knot), distance(Knot::default()) - 1);
+        }
+    }
+}
+
+/// Knots that are dragged diagonally have to move diagonally too, instead of
+/// lining up behind their leader.
+#[test]
+fn long_ropes_bend_like_in_the_puzzle() {
+    let mut rope = Rope::new(10);
+
+    for (direction, steps) in [(Direction::Right, 5), (Direction::Up, 8)] {
+        for _ in 0..steps {
+            rope.step(direction);
+        }
+    }
+
+    let expected = [
+        (5, 8),
+        (5, 7),
+        (5, 6),
+        (5, 5),
+        (5, 4),
+        (4, 4),
+        (3, 3),
+        (2, 2),
+        (1, 1),
+        (0, 0),
+    ];
+
+    assert_eq!(rope.knots(), expected.map(|(x, y)| Knot::new(x, y)));
+}
+
+#[test]
+fn long_ropes_match_the_larger_example() {
+    let motions = Day09::parse("R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20").unwrap();
+
+    assert_eq!(simulate::<2>(&motions, 10), 36);
+    assert!(simulate::<2>(&motions, 2) > simulate::<2>(&motions, 10));
+}
@@ -0,0 +1,179 @@
+use std::{collections::HashMap, fmt::Write};
+
+use common::ParseError;
+
+use crate::Operation;
+
+/// Addresses of labels, counted in instructions from the start of the
+/// program.
+type Labels<'a> = HashMap<&'a str, usize>;
+
+fn is_label(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Splits the labels off the front of `code`, returning them and whatever
+/// instruction follows.
+fn split_labels(code: &str) -> (Vec<&str>, &str) {
+    let mut labels = Vec::new();
+    let mut rest = code.trim_start();
+
+    while let Some((label, after)) = rest.split_once(':') {
+        let label = label.trim_end();
+
+        if !is_label(label) {
+            break;
+        }
+
+        labels.push(label);
+        rest = after.trim_start();
+    }
+
+    (labels, rest.trim_end())
+}
+
+/// Parses the instruction `code`, a slice of the line at 0-based `index`.
+/// `addx` takes either an integer or the name of a label, which stands for
+/// its address.
+fn parse_instruction(
+    index: usize,
+    line: &str,
+    code: &str,
+    labels: &Labels,
+) -> Result<Operation, ParseError> {
+    let tokens: Vec<&str> = code.split_ascii_whitespace().collect();
+
+    match tokens[..] {
+        ["noop"] => Ok(Operation::NOOP),
+        ["addx", y] => match (y.parse::<i32>(), labels.get(y)) {
+            (Ok(y), _) => Ok(Operation::ADD(y)),
+            (Err(_), Some(&address)) => Ok(Operation::ADD(address as i32)),
+            (Err(_), None) if is_label(y) => Err(ParseError::new(index, line, y, "unknown label")),
+            (Err(_), None) => Err(ParseError::new(
+                index,
+                line,
+                y,
+                "expected an integer operand",
+            )),
+        },
+        ["noop", extra, ..] | ["addx", _, extra, ..] => {
+            Err(ParseError::new(index, line, extra, "unexpected operand"))
+        }
+        ["addx"] => Err(ParseError::line(index, line, "addx needs an operand")),
+        [op, ..] => Err(ParseError::new(index, line, op, "unknown operation")),
+        [] => Err(ParseError::line(index, line, "expected an operation")),
+    }
+}
+
+/// Parses the instruction on the line at 0-based `index`, which has to be
+/// bare: no labels, comments or label operands.
+pub fn parse_operation(index: usize, line: &str) -> Result<Operation, ParseError> {
+    parse_instruction(index, line, line, &Labels::new())
+}
+
+/// Assembles a program with one instruction per line. Anything after a `;`
+/// is a comment, blank lines are skipped, and a line may start with any
+/// number of `label:`s, which name the address of the next instruction.
+///
+/// The first pass finds the address of every label, so the second can use
+/// labels as operands before they are defined.
+pub fn assemble(source: &str) -> Result<Vec<Operation>, ParseError> {
+    let mut labels = Labels::new();
+    let mut instructions = Vec::new();
+
+    for (index, line) in source.lines().enumerate() {
+        let code = match line.split_once(';') {
+            Some((code, _comment)) => code,
+            None => line,
+        };
+
+        let (names, instruction) = split_labels(code);
+
+        for name in names {
+            if labels.insert(name, instructions.len()).is_some() {
+                return Err(ParseError::new(
+                    index,
+                    line,
+                    name,
+                    "label is already defined",
+                ));
+            }
+        }
+
+        if !instruction.is_empty() {
+            instructions.push((index, line, instruction));
+        }
+    }
+
+    instructions
+        .into_iter()
+        .map(|(index, line, code)| parse_instruction(index, line, code, &labels))
+        .collect()
+}
+
+/// Writes `program` back as source, one instruction per line in the form
+/// [`assemble`] reads. Labels and comments are not kept, so this is the
+/// canonical form of any program.
+pub fn disassemble(program: &[Operation]) -> String {
+    let mut source = String::new();
+
+    for operation in program {
+        writeln!(source, "{operation}").unwrap();
+    }
+
+    source
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        assembler::{assemble, disassemble},
+        Operation,
+    };
+
+    const SAMPLE: &str = "noop\naddx 3\naddx -5\n";
+
+    #[test]
+    fn comments_blank_lines_and_labels_are_skipped() {
+        let source = "; the small example\n\nstart: noop ; does nothing\n\n  addx 3\nend:addx -5\n";
+
+        assert_eq!(assemble(source).unwrap(), assemble(SAMPLE).unwrap());
+    }
+
+    #[test]
+    fn labels_stand_for_their_address() {
+        let source = "addx end\nloop: noop\naddx loop\nend:\n";
+
+        assert_eq!(
+            assemble(source).unwrap(),
+            [Operation::ADD(3), Operation::NOOP, Operation::ADD(1)]
+        );
+    }
+
+    #[test]
+    fn label_errors_point_at_the_label() {
+        let error = assemble("a: noop\n  a: noop").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.reason, "label is already defined");
+
+        let error = assemble("noop ; fine\naddx nowhere ; not fine").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 6));
+        assert_eq!(error.reason, "unknown label");
+    }
+
+    #[test]
+    fn disassembly_round_trips() {
+        let program = assemble(SAMPLE).unwrap();
+
+        assert_eq!(disassemble(&program), SAMPLE);
+        assert_eq!(assemble(&disassemble(&program)).unwrap(), program);
+        assert_eq!(disassemble(&[]), "");
+    }
+}
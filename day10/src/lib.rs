@@ -0,0 +1,225 @@
+use std::{
+    collections::VecDeque,
+    fmt::{self, Display},
+};
+
+use common::{ParseError, Solution};
+
+pub mod assembler;
+
+pub use assembler::parse_operation;
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Operation {
+    NOOP,
+    ADD(i32),
+}
+
+/// Writes the operation as the instruction it was assembled from.
+impl Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operation::NOOP => write!(f, "noop"),
+            Operation::ADD(y) => write!(f, "addx {y}"),
+        }
+    }
+}
+
+#[derive(PartialEq)]
+pub enum CPUState {
+    Idle,
+    Executing(Operation, i32),
+}
+
+#[allow(clippy::upper_case_acronyms)]
+pub struct CPU {
+    pub x: i32,
+    state: CPUState,
+    pub clock: i32,
+    queue: VecDeque<Operation>,
+    pub signal_sum: i32,
+    pub pixelbuf: String,
+}
+
+impl CPU {
+    pub fn new() -> Self {
+        Self {
+            x: 1,
+            state: CPUState::Idle,
+            clock: 0,
+            queue: VecDeque::new(),
+            signal_sum: 0,
+            pixelbuf: String::new(),
+        }
+    }
+
+    pub fn load_next_instruction(&mut self) {
+        if let Some(op) = self.queue.pop_front() {
+            self.state = CPUState::Executing(op, self.clock);
+        } else {
+            self.state = CPUState::Idle;
+        }
+    }
+
+    pub fn tick(&mut self) {
+        match &self.state {
+            CPUState::Idle => {
+                self.load_next_instruction();
+            }
+
+            CPUState::Executing(op, since) => match op {
+                Operation::NOOP => {
+                    self.load_next_instruction();
+                }
+                Operation::ADD(y) => {
+                    if self.clock - since == 2 {
+                        self.x += *y;
+                        self.load_next_instruction();
+                    }
+                }
+            },
+        }
+
+        if !(self.state == CPUState::Idle) {
+
+            if ((self.x - 1)..=(self.x + 1)).contains(&(self.clock % 40)) {
+                self.pixelbuf.push('#');
+            } else {
+                self.pixelbuf.push('.')
+            }
+            
+            self.clock += 1;
+            
+            if self.clock % 40 == 0 {
+                self.pixelbuf.push('\n');
+            }
+
+            if (self.clock + 20) % 40 == 0 {
+                self.signal_sum += self.x * self.clock;
+            }
+        }
+
+    }
+
+    pub fn queue_op(&mut self, op: Operation) {
+        self.queue.push_back(op);
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.queue.is_empty() && self.state == CPUState::Idle
+    }
+}
+
+impl Default for CPU {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub mod part1 {
+    use crate::{Operation, CPU};
+
+    pub fn solve(program: &[Operation]) -> i32 {
+        let mut cpu = CPU::new();
+
+        program.iter().for_each(|&op| cpu.queue_op(op));
+
+        while !cpu.is_done() {
+            cpu.tick();
+        }
+
+        cpu.signal_sum
+    }
+}
+
+pub mod part2 {
+    use crate::{Operation, CPU};
+
+    pub fn solve(program: &[Operation]) -> String {
+        let mut cpu = CPU::new();
+
+        program.iter().for_each(|&op| cpu.queue_op(op));
+
+        while !cpu.is_done() {
+            cpu.tick();
+        }
+
+        cpu.pixelbuf
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Operation>;
+    type Part1 = i32;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        assembler::assemble(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day10;
+    use common::Solution;
+    const TEST_INPUT: &str = "addx 15\naddx -11\naddx 6\naddx -3\naddx 5\naddx -1\naddx -8\naddx 13\naddx 4\nnoop\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx -35\naddx 1\naddx 24\naddx -19\naddx 1\naddx 16\naddx -11\nnoop\nnoop\naddx 21\naddx -15\nnoop\nnoop\naddx -3\naddx 9\naddx 1\naddx -3\naddx 8\naddx 1\naddx 5\nnoop\nnoop\nnoop\nnoop\nnoop\naddx -36\nnoop\naddx 1\naddx 7\nnoop\nnoop\nnoop\naddx 2\naddx 6\nnoop\nnoop\nnoop\nnoop\nnoop\naddx 1\nnoop\nnoop\naddx 7\naddx 1\nnoop\naddx -13\naddx 13\naddx 7\nnoop\naddx 1\naddx -33\nnoop\nnoop\nnoop\naddx 2\nnoop\nnoop\nnoop\naddx 8\nnoop\naddx -1\naddx 2\naddx 1\nnoop\naddx 17\naddx -9\naddx 1\naddx 1\naddx -3\naddx 11\nnoop\nnoop\naddx 1\nnoop\naddx 1\nnoop\nnoop\naddx -13\naddx -19\naddx 1\naddx 3\naddx 26\naddx -30\naddx 12\naddx -1\naddx 3\naddx 1\nnoop\nnoop\nnoop\naddx -9\naddx 18\naddx 1\naddx 2\nnoop\nnoop\naddx 9\nnoop\nnoop\nnoop\naddx -1\naddx 2\naddx -37\naddx 1\naddx 3\nnoop\naddx 15\naddx -21\naddx 22\naddx -6\naddx 1\nnoop\naddx 2\naddx 1\nnoop\naddx -10\nnoop\nnoop\naddx 20\naddx 1\naddx 2\naddx 2\naddx -6\naddx -11\nnoop\nnoop\nnoop\n";
+    const TEST_OUTPUT: &str = "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....
+";
+
+    #[test]
+    fn validate_part1() {
+        assert_eq!(Day10::part1(&Day10::parse(TEST_INPUT).unwrap()), 13140);
+    }
+
+    #[test]
+    fn validate_part2() {
+        assert_eq!(
+            Day10::part2(&Day10::parse(TEST_INPUT).unwrap()),
+            TEST_OUTPUT
+        );
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_offending_token() {
+        let error = Day10::parse("noop\naddx 1O").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 6));
+        assert_eq!(error.text, "1O");
+
+        let error = Day10::parse("noop\nmulx 2").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn sample_program_round_trips() {
+        use crate::assembler::{assemble, disassemble};
+
+        let program = Day10::parse(TEST_INPUT).unwrap();
+
+        assert_eq!(disassemble(&program), TEST_INPUT);
+
+        let annotated = TEST_INPUT.replacen("noop\n", "start: noop ; first idle cycle\n\n", 1);
+
+        assert_eq!(assemble(&annotated).unwrap(), program);
+        assert_eq!(Day10::part1(&assemble(&annotated).unwrap()), 13140);
+    }
+}
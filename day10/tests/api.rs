@@ -0,0 +1,23 @@
+use day10::{parse_operation, Operation, CPU};
+
+#[test]
+fn parse_operation_reads_both_instructions() {
+    assert!(parse_operation(0, "noop") == Ok(Operation::NOOP));
+    assert!(parse_operation(0, "addx -7") == Ok(Operation::ADD(-7)));
+}
+
+#[test]
+fn addx_takes_two_cycles() {
+    let mut cpu = CPU::new();
+
+    cpu.queue_op(Operation::NOOP);
+    cpu.queue_op(Operation::ADD(3));
+    cpu.queue_op(Operation::ADD(-5));
+
+    while !cpu.is_done() {
+        cpu.tick();
+    }
+
+    assert_eq!(cpu.clock, 5);
+    assert_eq!(cpu.x, -1);
+}
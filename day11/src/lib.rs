@@ -0,0 +1,412 @@
+use std::collections::VecDeque;
+
+use common::{ParseError, Solution};
+
+#[derive(Clone, Debug)]
+pub struct Test {
+    pub divisor: u128,
+    pub true_receiver: usize,
+    pub false_receiver: usize,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum WorryLevel {
+    Old,
+    Value(u128),
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum Operation {
+    Add(WorryLevel),
+    Multiply(WorryLevel),
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum ReliefMechanism {
+    DivideByThree,
+    Modulo,
+}
+
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    pub items: VecDeque<u128>,
+    pub test: Test,
+    pub op: Operation,
+    relief_mechanism: ReliefMechanism,
+    denominator: Option<u128>,
+}
+
+impl Monkey {
+    pub fn inspect_and_fling(&mut self) -> Vec<(u128, usize)> {
+        let mut passes: Vec<(u128, usize)> = Vec::new();
+
+        while let Some(mut item) = self.items.pop_front() {
+            item = match &self.op {
+                Operation::Add(level) => match level {
+                    WorryLevel::Old => item + item,
+                    WorryLevel::Value(val) => item + val,
+                },
+                Operation::Multiply(level) => match level {
+                    WorryLevel::Old => match item.checked_mul(item) {
+                        Some(product) => product,
+                        None => panic!("This number is too fuckin big"),
+                    },
+                    WorryLevel::Value(val) => match item.checked_mul(*val) {
+                        Some(product) => product,
+                        None => panic!("This number is way huge"),
+                    },
+                },
+            };
+
+            match self.relief_mechanism {
+                ReliefMechanism::DivideByThree => {
+                    item /= 3;
+                }
+                ReliefMechanism::Modulo => {
+                    if let Some(denominator) = self.denominator {
+                        item %= denominator;
+                    }
+                }
+            }
+
+            let receiver = if item % self.test.divisor == 0 {
+                self.test.true_receiver
+            } else {
+                self.test.false_receiver
+            };
+
+            passes.push((item, receiver));
+        }
+
+        passes
+    }
+
+    pub fn give(&mut self, item: u128) {
+        self.items.push_back(item);
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Gang {
+    pub monkeys: Vec<Monkey>,
+    pub fling_counts: Vec<u128>,
+}
+
+impl Gang {
+    pub fn new() -> Self {
+        Self {
+            monkeys: Vec::new(),
+            fling_counts: Vec::new(),
+        }
+    }
+
+    pub fn fling_stuff_around(&mut self) {
+        for flinger in 0..self.monkeys.len() {
+            let flings = self.monkeys[flinger].inspect_and_fling();
+
+            for fling in flings {
+                self.fling_counts[flinger] += 1;
+
+                let (thing, catcher) = fling;
+                self.monkeys[catcher].give(thing);
+            }
+        }
+    }
+
+    pub fn add_monkey(&mut self, monkey: Monkey) {
+        self.monkeys.push(monkey);
+        self.fling_counts.push(0);
+    }
+
+    pub fn set_relief_mechanism(&mut self, relief_mechanism: ReliefMechanism) {
+        for monkey in self.monkeys.iter_mut() {
+            monkey.relief_mechanism = relief_mechanism;
+        }
+    }
+}
+
+/// Returns what follows `prefix` on the line at 0-based `index`, ignoring
+/// the line's indentation.
+fn field<'a>(lines: &[&'a str], index: usize, prefix: &str) -> Result<&'a str, ParseError> {
+    let line = match lines.get(index) {
+        Some(line) => *line,
+        None => {
+            return Err(ParseError::line(
+                index,
+                "",
+                format!("expected \"{prefix}\""),
+            ))
+        }
+    };
+
+    match line.trim_start().strip_prefix(prefix) {
+        Some(rest) => Ok(rest),
+        None => Err(ParseError::line(
+            index,
+            line,
+            format!("expected \"{prefix}\""),
+        )),
+    }
+}
+
+pub fn parse_monkeys(input: &str) -> Result<Gang, ParseError> {
+    let mut monkeys = Gang::new();
+
+    let mut denominator = 1u128;
+
+    let lines = input.lines().collect::<Vec<&str>>();
+    let monkey_count = lines.chunks(7).len();
+
+    for start in (0..lines.len()).step_by(7) {
+        field(&lines, start, "Monkey ")?;
+
+        let items = field(&lines, start + 1, "Starting items:")?
+            .split(',')
+            .map(str::trim)
+            .filter(|str| !str.is_empty())
+            .map(|str| match str.parse::<u128>() {
+                Ok(number) => Ok(number),
+                Err(_) => Err(ParseError::new(
+                    start + 1,
+                    lines[start + 1],
+                    str,
+                    "expected an item worry level",
+                )),
+            })
+            .collect::<Result<VecDeque<u128>, ParseError>>()?;
+
+        let operation = field(&lines, start + 2, "Operation: new = old")?;
+        let op = match operation.split_whitespace().collect::<Vec<&str>>()[..] {
+            [operator, operand] => {
+                let level = match operand {
+                    "old" => WorryLevel::Old,
+                    _ => WorryLevel::Value(match operand.parse::<u128>() {
+                        Ok(value) => value,
+                        Err(_) => {
+                            return Err(ParseError::new(
+                                start + 2,
+                                lines[start + 2],
+                                operand,
+                                "expected a number or \"old\"",
+                            ))
+                        }
+                    }),
+                };
+
+                match operator {
+                    "+" => Operation::Add(level),
+                    "*" => Operation::Multiply(level),
+                    _ => {
+                        return Err(ParseError::new(
+                            start + 2,
+                            lines[start + 2],
+                            operator,
+                            "expected + or *",
+                        ))
+                    }
+                }
+            }
+            _ => {
+                return Err(ParseError::line(
+                    start + 2,
+                    lines[start + 2],
+                    "expected an operator followed by an operand",
+                ))
+            }
+        };
+
+        let number = |index: usize, prefix: &str, reason: &str| {
+            let value = field(&lines, index, prefix)?.trim();
+
+            match value.parse::<u128>() {
+                Ok(value) => Ok(value),
+                Err(_) => Err(ParseError::new(index, lines[index], value, reason)),
+            }
+        };
+
+        let receiver = |index: usize, prefix: &str| {
+            let receiver = number(index, prefix, "expected a monkey number")?;
+
+            if receiver >= monkey_count as u128 {
+                let value = field(&lines, index, prefix)?.trim();
+
+                return Err(ParseError::new(
+                    index,
+                    lines[index],
+                    value,
+                    format!("there are only {monkey_count} monkeys"),
+                ));
+            }
+
+            Ok(receiver as usize)
+        };
+
+        let test = Test {
+            divisor: match number(start + 3, "Test: divisible by", "expected a divisor")? {
+                0 => {
+                    return Err(ParseError::line(
+                        start + 3,
+                        lines[start + 3],
+                        "cannot test divisibility by zero",
+                    ))
+                }
+                divisor => divisor,
+            },
+            true_receiver: receiver(start + 4, "If true: throw to monkey")?,
+            false_receiver: receiver(start + 5, "If false: throw to monkey")?,
+        };
+
+        denominator *= test.divisor;
+
+        monkeys.add_monkey(Monkey {
+            items,
+            op,
+            test,
+            relief_mechanism: ReliefMechanism::DivideByThree,
+            denominator: None,
+        });
+    }
+
+    for monkey in monkeys.monkeys.iter_mut() {
+        monkey.denominator = Some(denominator);
+    }
+
+    Ok(monkeys)
+}
+
+pub mod part1 {
+    use crate::{Gang, ReliefMechanism};
+
+    pub fn solve(gang: &Gang) -> u128 {
+        let mut monkeys = gang.clone();
+        monkeys.set_relief_mechanism(ReliefMechanism::DivideByThree);
+
+        for _ in 0..20 {
+            monkeys.fling_stuff_around();
+        }
+
+        let mut counts = monkeys.fling_counts;
+
+        counts.sort();
+
+        match counts
+            .iter()
+            .rev()
+            .take(2)
+            .copied()
+            .reduce(|accumulator, element| accumulator * element)
+        {
+            Some(businesslevel) => businesslevel,
+            None => panic!("Not enough monkeys"),
+        }
+    }
+}
+
+pub mod part2 {
+    use crate::{Gang, ReliefMechanism};
+
+    pub fn solve(gang: &Gang) -> u128 {
+        let mut monkeys = gang.clone();
+        monkeys.set_relief_mechanism(ReliefMechanism::Modulo);
+
+        for _ in 0..10000 {
+            monkeys.fling_stuff_around();
+        }
+
+        let mut counts = monkeys.fling_counts;
+
+        counts.sort();
+
+        match counts
+            .iter()
+            .rev()
+            .take(2)
+            .copied()
+            .reduce(|accumulator, element| accumulator * element)
+        {
+            Some(businesslevel) => businesslevel,
+            None => panic!("Not enough monkeys"),
+        }
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Gang;
+    type Part1 = u128;
+    type Part2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_monkeys(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day11;
+    use common::Solution;
+    const TEST_INPUT: &str = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+  
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+
+    #[test]
+    fn validate_part1() {
+        assert_eq!(Day11::part1(&Day11::parse(TEST_INPUT).unwrap()), 10_605);
+    }
+
+    #[test]
+    fn validate_part2() {
+        assert_eq!(
+            Day11::part2(&Day11::parse(TEST_INPUT).unwrap()),
+            2_713_310_158
+        );
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_offending_token() {
+        let error = Day11::parse(&TEST_INPUT.replace("old * 19", "old / 19")).unwrap_err();
+
+        assert_eq!((error.line, error.column), (3, 24));
+        assert_eq!(error.text, "/");
+
+        let error = Day11::parse(&TEST_INPUT.replace("monkey 3", "monkey 4")).unwrap_err();
+
+        assert_eq!((error.line, error.column), (6, 31));
+        assert_eq!(error.reason, "there are only 4 monkeys");
+    }
+}
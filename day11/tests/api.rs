@@ -0,0 +1,34 @@
+use day11::{parse_monkeys, ReliefMechanism};
+
+const MONKEYS: &str = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 1
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items: 54
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 0
+    If false: throw to monkey 0";
+
+#[test]
+fn parse_monkeys_reads_every_monkey() {
+    let gang = parse_monkeys(MONKEYS).unwrap();
+
+    assert_eq!(gang.monkeys.len(), 2);
+    assert_eq!(Vec::from(gang.monkeys[0].items.clone()), vec![79, 98]);
+    assert_eq!(gang.monkeys[1].test.divisor, 19);
+}
+
+#[test]
+fn a_round_counts_every_fling() {
+    let mut gang = parse_monkeys(MONKEYS).unwrap();
+
+    gang.set_relief_mechanism(ReliefMechanism::DivideByThree);
+    gang.fling_stuff_around();
+
+    assert_eq!(gang.fling_counts, vec![2, 3]);
+}
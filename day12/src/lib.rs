@@ -0,0 +1,168 @@
+use std::collections::VecDeque;
+
+use common::{ParseError, Solution};
+use grid::Grid;
+
+pub use grid::Position;
+
+pub struct Graph {
+    pub heights: Grid<usize>,
+    pub s: Option<Position>,
+    pub e: Option<Position>,
+}
+
+impl Graph {
+    /// Positions that can be reached in one step from `position`, which are
+    /// at most one higher than it.
+    pub fn neighbours(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        let height = self.heights[position];
+
+        self.heights
+            .neighbours4(position)
+            .filter(move |&neighbour| self.heights[neighbour] <= height + 1)
+    }
+
+    pub fn find_path(&self, from: Position, to: Position) -> Option<Vec<Position>> {
+        let (width, height) = (self.heights.width(), self.heights.height());
+        let mut queue = VecDeque::new();
+        let mut explored = Grid::new(width, height, false);
+        let mut prev = Grid::new(width, height, None);
+
+        explored[from] = true;
+        queue.push_back(from);
+
+        let mut pos = loop {
+            let pos = queue.pop_front()?;
+
+            if pos == to {
+                break pos;
+            } else {
+                for neighbour in self.neighbours(pos) {
+                    if !explored[neighbour] {
+                        explored[neighbour] = true;
+                        prev[neighbour] = Some(pos);
+                        queue.push_back(neighbour);
+                    }
+                }
+            }
+        };
+
+        let mut path = vec![];
+
+        while pos != from {
+            pos = prev[pos].unwrap();
+            path.push(pos);
+        }
+
+        Some(path)
+    }
+}
+
+pub fn letter_to_height(letter: char) -> usize {
+    match letter {
+        'S' => 'a'.to_digit(36),
+        'E' => 'z'.to_digit(36),
+        _ => letter.to_digit(36),
+    }
+    .unwrap() as usize
+}
+
+pub fn parse_graph(input: &str) -> Result<Graph, ParseError> {
+    let map = Grid::parse(input, |c| match c {
+        'a'..='z' | 'S' | 'E' => Ok(c),
+        _ => Err("expected a height from a to z, S or E"),
+    })?;
+
+    let find = |marker| {
+        map.iter()
+            .find(|&(_, &c)| c == marker)
+            .map(|(position, _)| position)
+    };
+
+    Ok(Graph {
+        heights: map.map(|&c| letter_to_height(c)),
+        s: find('S'),
+        e: find('E'),
+    })
+}
+
+pub mod part1 {
+    use crate::Graph;
+
+    pub fn solve(graph: &Graph) -> usize {
+        match (graph.s, graph.e) {
+            (Some(s), Some(e)) => match graph.find_path(s, e) {
+                Some(path) => path.len(),
+                None => 0,
+            },
+            _ => 0,
+        }
+    }
+}
+
+pub mod part2 {
+    use crate::{letter_to_height, Graph};
+
+    pub fn solve(graph: &Graph) -> usize {
+        match graph.e {
+            Some(e) => {
+                graph
+                    .heights
+                    .iter()
+                    .filter(|&(_, &height)| height == letter_to_height('a'))
+                    .filter_map(|(position, _)| graph.find_path(position, e))
+                    .map(|path| path.len())
+                    .min()
+                    .unwrap()
+            }
+            None => 0,
+        }
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Graph;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_graph(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day12;
+    use common::Solution;
+    const TEST_INPUT: &str = "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi";
+
+    #[test]
+    fn validate_part1() {
+        assert_eq!(Day12::part1(&Day12::parse(TEST_INPUT).unwrap()), 31);
+    }
+
+    #[test]
+    fn validate_part2() {
+        assert_eq!(Day12::part2(&Day12::parse(TEST_INPUT).unwrap()), 29);
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_offending_token() {
+        let error = Day12::parse("Sabq\nab#r").err().unwrap();
+
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.text, "#");
+    }
+}
@@ -0,0 +1,25 @@
+use day12::{letter_to_height, parse_graph, Position};
+
+#[test]
+fn start_and_end_have_fixed_heights() {
+    assert_eq!(letter_to_height('S'), letter_to_height('a'));
+    assert_eq!(letter_to_height('E'), letter_to_height('z'));
+}
+
+#[test]
+fn find_path_returns_every_step() {
+    let graph = parse_graph("Sbcdefghijklmnopqrstuvwxyz\nzzzzzzzzzzzzzzzzzzzzzzzzzE").unwrap();
+    let path = graph.find_path(graph.s.unwrap(), Position { x: 25, y: 0 });
+
+    assert_eq!(path.map(|path| path.len()), Some(25));
+    assert_eq!(graph.e, Some(Position { x: 25, y: 1 }));
+}
+
+#[test]
+fn unreachable_targets_have_no_path() {
+    let graph = parse_graph("SzE").unwrap();
+
+    assert!(graph
+        .find_path(graph.s.unwrap(), graph.e.unwrap())
+        .is_none());
+}
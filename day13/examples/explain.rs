@@ -0,0 +1,59 @@
+//! Prints how every pair of packets is compared, or just the pair given.
+//!
+//!     cargo run -p day13 --example explain
+//!     cargo run -p day13 --example explain -- '[[[]]]' '[[]]'
+
+use std::{env, process::exit};
+
+use common::{input, Solution};
+use day13::{explain, Day13, Packet};
+
+fn parse(line: &str) -> Packet {
+    match line.parse() {
+        Ok(packet) => packet,
+        Err(error) => {
+            eprintln!("Could not parse packet: {error}");
+            exit(1);
+        }
+    }
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    let pairs = match args.as_slice() {
+        [left, right] => vec![(parse(left), parse(right))],
+        [] => {
+            let input = match input::load(Day13::DAY, None) {
+                Ok(input) => input,
+                Err(error) => {
+                    eprintln!("{error}");
+                    exit(1);
+                }
+            };
+
+            match Day13::parse(&input) {
+                Ok(pairs) => pairs,
+                Err(error) => {
+                    eprintln!("Could not parse input: {error}");
+                    exit(1);
+                }
+            }
+        }
+        _ => {
+            eprintln!("Usage: explain [LEFT RIGHT]");
+            exit(2);
+        }
+    };
+
+    for (index, (left, right)) in pairs.iter().enumerate() {
+        let explanation = explain::compare(left, right);
+
+        println!("== Pair {} ==", index + 1);
+        print!("{explanation}");
+        println!(
+            "Decided at {:?}: {}\n",
+            explanation.path, explanation.reason
+        );
+    }
+}
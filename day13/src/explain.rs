@@ -0,0 +1,284 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+};
+
+use crate::Packet;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+/// What decided a comparison.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Reason {
+    LeftSmaller {
+        left: u32,
+        right: u32,
+    },
+    RightSmaller {
+        left: u32,
+        right: u32,
+    },
+    LeftRanOut,
+    RightRanOut,
+    /// Nothing did, the packets are equal.
+    Equal,
+}
+
+impl Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reason::LeftSmaller { left, right } => {
+                write!(f, "left side is smaller ({left} < {right})")
+            }
+            Reason::RightSmaller { left, right } => {
+                write!(f, "right side is smaller ({left} > {right})")
+            }
+            Reason::LeftRanOut => write!(f, "left side ran out of items"),
+            Reason::RightRanOut => write!(f, "right side ran out of items"),
+            Reason::Equal => write!(f, "packets are equal"),
+        }
+    }
+}
+
+/// How a comparison of two packets was decided, as found by [`compare`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Explanation {
+    pub ordering: Ordering,
+    /// Indices into the nested lists of both packets leading to where the
+    /// comparison was decided. An integer promoted to a list is indexed as
+    /// that one element list.
+    pub path: Vec<usize>,
+    pub reason: Reason,
+    /// Paths at which an integer was promoted to a list, and on which side.
+    pub promotions: Vec<(Vec<usize>, Side)>,
+    trace: Vec<String>,
+}
+
+impl Explanation {
+    pub fn in_right_order(&self) -> bool {
+        self.ordering == Ordering::Less
+    }
+}
+
+/// The trace of every comparison made, in the style of the puzzle text.
+impl Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in &self.trace {
+            writeln!(f, "{line}")?;
+        }
+
+        Ok(())
+    }
+}
+
+struct Comparison {
+    path: Vec<usize>,
+    promotions: Vec<(Vec<usize>, Side)>,
+    trace: Vec<String>,
+}
+
+impl Comparison {
+    fn log(&mut self, depth: usize, line: impl Display) {
+        self.trace.push(format!("{}- {line}", "  ".repeat(depth)));
+    }
+
+    fn decide(
+        &mut self,
+        depth: usize,
+        ordering: Ordering,
+        reason: Reason,
+    ) -> Option<(Ordering, Reason)> {
+        let verdict = match ordering {
+            Ordering::Less => "in the right order",
+            _ => "not in the right order",
+        };
+
+        let mut reason_text = reason.to_string();
+        reason_text[..1].make_ascii_uppercase();
+        self.log(
+            depth,
+            format_args!("{reason_text}, so inputs are {verdict}"),
+        );
+
+        Some((ordering, reason))
+    }
+
+    fn compare(
+        &mut self,
+        left: &Packet,
+        right: &Packet,
+        depth: usize,
+    ) -> Option<(Ordering, Reason)> {
+        self.log(depth, format_args!("Compare {left} vs {right}"));
+
+        match (left, right) {
+            (&Packet::Integer(l), &Packet::Integer(r)) => match l.cmp(&r) {
+                Ordering::Less => self.decide(
+                    depth + 1,
+                    Ordering::Less,
+                    Reason::LeftSmaller { left: l, right: r },
+                ),
+                Ordering::Greater => self.decide(
+                    depth + 1,
+                    Ordering::Greater,
+                    Reason::RightSmaller { left: l, right: r },
+                ),
+                Ordering::Equal => None,
+            },
+            (Packet::List(l), Packet::List(r)) => {
+                for index in 0.. {
+                    match (l.get(index), r.get(index)) {
+                        (Some(left), Some(right)) => {
+                            self.path.push(index);
+
+                            if let Some(decided) = self.compare(left, right, depth + 1) {
+                                return Some(decided);
+                            }
+
+                            self.path.pop();
+                        }
+                        (None, Some(_)) => {
+                            return self.decide(depth + 1, Ordering::Less, Reason::LeftRanOut)
+                        }
+                        (Some(_), None) => {
+                            return self.decide(depth + 1, Ordering::Greater, Reason::RightRanOut)
+                        }
+                        (None, None) => break,
+                    }
+                }
+
+                None
+            }
+            (&Packet::Integer(l), Packet::List(_)) => {
+                let promoted = Packet::List(vec![Packet::Integer(l)]);
+
+                self.log(
+                    depth + 1,
+                    format_args!("Mixed types; convert left to {promoted} and retry comparison"),
+                );
+                self.promotions.push((self.path.clone(), Side::Left));
+                self.compare(&promoted, right, depth + 1)
+            }
+            (Packet::List(_), &Packet::Integer(r)) => {
+                let promoted = Packet::List(vec![Packet::Integer(r)]);
+
+                self.log(
+                    depth + 1,
+                    format_args!("Mixed types; convert right to {promoted} and retry comparison"),
+                );
+                self.promotions.push((self.path.clone(), Side::Right));
+                self.compare(left, &promoted, depth + 1)
+            }
+        }
+    }
+}
+
+/// Compares two packets like [`Ord`] does, but keeps track of how the result
+/// was arrived at.
+pub fn compare(left: &Packet, right: &Packet) -> Explanation {
+    let mut comparison = Comparison {
+        path: Vec::new(),
+        promotions: Vec::new(),
+        trace: Vec::new(),
+    };
+
+    let (ordering, reason) = match comparison.compare(left, right, 0) {
+        Some(decided) => decided,
+        None => {
+            comparison.log(1, "Packets are equal");
+            comparison.path.clear();
+            (Ordering::Equal, Reason::Equal)
+        }
+    };
+
+    Explanation {
+        ordering,
+        path: comparison.path,
+        reason,
+        promotions: comparison.promotions,
+        trace: comparison.trace,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use crate::{
+        explain::{compare, Reason, Side},
+        parse_packet, Packet,
+    };
+
+    fn packet(line: &str) -> Packet {
+        parse_packet(0, line).unwrap()
+    }
+
+    #[test]
+    fn explanations_agree_with_ord() {
+        let pairs = [
+            ("[1,1,3,1,1]", "[1,1,5,1,1]"),
+            ("[[1],[2,3,4]]", "[[1],4]"),
+            ("[9]", "[[8,7,6]]"),
+            ("[[4,4],4,4]", "[[4,4],4,4,4]"),
+            ("[7,7,7,7]", "[7,7,7]"),
+            ("[]", "[3]"),
+            ("[[[]]]", "[[]]"),
+            ("[1,[2,[3,[4,[5,6,7]]]],8,9]", "[1,[2,[3,[4,[5,6,0]]]],8,9]"),
+            ("[[2]]", "[2]"),
+        ];
+
+        for (left, right) in pairs {
+            let (left, right) = (packet(left), packet(right));
+
+            assert_eq!(
+                compare(&left, &right).ordering,
+                left.cmp(&right),
+                "{left} vs {right}"
+            );
+        }
+    }
+
+    #[test]
+    fn nested_empty_lists_run_out_on_the_right() {
+        let explanation = compare(&packet("[[[]]]"), &packet("[[]]"));
+
+        assert_eq!(explanation.ordering, Ordering::Greater);
+        assert_eq!(explanation.path, [0]);
+        assert_eq!(explanation.reason, Reason::RightRanOut);
+        assert_eq!(
+            explanation.to_string(),
+            "- Compare [[[]]] vs [[]]
+  - Compare [[]] vs []
+    - Right side ran out of items, so inputs are not in the right order
+"
+        );
+    }
+
+    #[test]
+    fn promotions_are_traced() {
+        let explanation = compare(&packet("[[1],[2,3,4]]"), &packet("[[1],4]"));
+
+        assert!(explanation.in_right_order());
+        assert_eq!(explanation.path, [1, 0]);
+        assert_eq!(
+            explanation.reason,
+            Reason::LeftSmaller { left: 2, right: 4 }
+        );
+        assert_eq!(explanation.promotions, [(vec![1], Side::Right)]);
+        assert!(explanation
+            .to_string()
+            .contains("    - Mixed types; convert right to [4] and retry comparison\n"));
+    }
+
+    #[test]
+    fn equal_packets_have_an_empty_path() {
+        let explanation = compare(&packet("[1,[2]]"), &packet("[1,[2]]"));
+
+        assert_eq!(explanation.reason, Reason::Equal);
+        assert!(explanation.path.is_empty());
+    }
+}
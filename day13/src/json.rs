@@ -0,0 +1,133 @@
+use std::fmt;
+
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    ser::SerializeSeq,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use serde_json::Value;
+
+use crate::Packet;
+
+impl Serialize for Packet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Packet::Integer(integer) => serializer.serialize_u32(*integer),
+            Packet::List(list) => {
+                let mut seq = serializer.serialize_seq(Some(list.len()))?;
+
+                for packet in list {
+                    seq.serialize_element(packet)?;
+                }
+
+                seq.end()
+            }
+        }
+    }
+}
+
+struct PacketVisitor;
+
+impl<'de> Visitor<'de> for PacketVisitor {
+    type Value = Packet;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a non-negative integer or a list of packets")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Packet, E> {
+        match u32::try_from(value) {
+            Ok(integer) => Ok(Packet::Integer(integer)),
+            Err(_) => Err(E::invalid_value(de::Unexpected::Unsigned(value), &self)),
+        }
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Packet, E> {
+        match u64::try_from(value) {
+            Ok(value) => self.visit_u64(value),
+            Err(_) => Err(E::invalid_value(de::Unexpected::Signed(value), &self)),
+        }
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Packet, A::Error> {
+        let mut list = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+
+        while let Some(packet) = seq.next_element()? {
+            list.push(packet);
+        }
+
+        Ok(Packet::List(list))
+    }
+}
+
+impl<'de> Deserialize<'de> for Packet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(PacketVisitor)
+    }
+}
+
+impl From<&Packet> for Value {
+    fn from(packet: &Packet) -> Self {
+        match packet {
+            Packet::Integer(integer) => Value::from(*integer),
+            Packet::List(list) => Value::Array(list.iter().map(Value::from).collect()),
+        }
+    }
+}
+
+impl From<Packet> for Value {
+    fn from(packet: Packet) -> Self {
+        Value::from(&packet)
+    }
+}
+
+impl TryFrom<&Value> for Packet {
+    type Error = serde_json::Error;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        Packet::deserialize(value)
+    }
+}
+
+impl TryFrom<Value> for Packet {
+    type Error = serde_json::Error;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        Packet::deserialize(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use crate::{parse_packet, Packet};
+
+    #[test]
+    fn packets_round_trip_through_json() {
+        for line in ["[]", "[1,[2,[3,[4,[5,6,7]]]],8,9]", "[[[]],10,[0]]"] {
+            let packet = parse_packet(0, line).unwrap();
+            let json = serde_json::to_string(&packet).unwrap();
+            let back = serde_json::from_str::<Packet>(&json).unwrap();
+
+            assert_eq!(json, line);
+            assert_eq!(back.to_string(), line);
+        }
+    }
+
+    #[test]
+    fn packets_convert_to_and_from_values() {
+        let packet = parse_packet(0, "[1,[2,[]]]").unwrap();
+        let value = Value::from(&packet);
+
+        assert_eq!(value, json!([1, [2, []]]));
+        assert_eq!(Packet::try_from(value).unwrap().to_string(), "[1,[2,[]]]");
+    }
+
+    #[test]
+    fn non_packet_json_is_rejected() {
+        for json in ["[1,-2]", "[4294967296]", "[1.5]", "\"[1]\"", "{\"a\":1}"] {
+            assert!(serde_json::from_str::<Packet>(json).is_err(), "{json}");
+        }
+    }
+}
@@ -0,0 +1,344 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    slice,
+    str::FromStr,
+};
+
+use common::{ParseError, Solution};
+
+pub mod explain;
+mod json;
+
+#[derive(Clone, Debug)]
+pub enum Packet {
+    Integer(u32),
+    List(Vec<Packet>),
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Packet::Integer(left), Packet::Integer(right)) => left.cmp(right),
+            // Lists compare element by element, and the shorter one comes
+            // first if they agree for as long as both last
+            (Packet::List(left), Packet::List(right)) => left.cmp(right),
+            // A lone integer compares as if it were a list holding just it
+            (Packet::Integer(_), Packet::List(right)) => slice::from_ref(self).cmp(right),
+            (Packet::List(left), Packet::Integer(_)) => left.as_slice().cmp(slice::from_ref(other)),
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Packets are equal when neither is in the right order compared to the
+/// other, so `2`, `[2]` and `[[2]]` are all equal to each other.
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Packet {}
+
+/// Writes the packet in the same bracket form it was read from, which is
+/// also its compact JSON representation.
+impl Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Packet::Integer(integer) => write!(f, "{integer}"),
+            Packet::List(list) => {
+                write!(f, "[")?;
+
+                for (index, packet) in list.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+
+                    write!(f, "{packet}")?;
+                }
+
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+impl FromStr for Packet {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        parse_packet(0, line)
+    }
+}
+
+struct Parser<'a> {
+    index: usize,
+    line: &'a str,
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.line.as_bytes().get(self.position).copied()
+    }
+
+    fn error(&self, reason: &str) -> ParseError {
+        let rest = &self.line[self.position..];
+        let token = match rest.chars().next() {
+            Some(c) => &rest[..c.len_utf8()],
+            None => rest,
+        };
+
+        ParseError::new(self.index, self.line, token, reason)
+    }
+
+    fn packet(&mut self) -> Result<Packet, ParseError> {
+        match self.peek() {
+            Some(b'[') => self.list(),
+            Some(b'0'..=b'9') => self.integer(),
+            _ => Err(self.error("expected an integer or a list")),
+        }
+    }
+
+    fn list(&mut self) -> Result<Packet, ParseError> {
+        let mut list = Vec::new();
+
+        self.position += 1;
+
+        if self.peek() == Some(b']') {
+            self.position += 1;
+            return Ok(Packet::List(list));
+        }
+
+        loop {
+            list.push(self.packet()?);
+
+            match self.peek() {
+                Some(b',') => self.position += 1,
+                Some(b']') => {
+                    self.position += 1;
+                    return Ok(Packet::List(list));
+                }
+                _ => return Err(self.error("expected , or ]")),
+            }
+        }
+    }
+
+    fn integer(&mut self) -> Result<Packet, ParseError> {
+        let start = self.position;
+
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.position += 1;
+        }
+
+        let digits = &self.line[start..self.position];
+
+        match digits.parse() {
+            Ok(integer) => Ok(Packet::Integer(integer)),
+            Err(_) => Err(ParseError::new(
+                self.index,
+                self.line,
+                digits,
+                "integer is too large",
+            )),
+        }
+    }
+}
+
+/// Parses the packet on the line at 0-based `index`, which has to make up the
+/// whole line.
+pub fn parse_packet(index: usize, line: &str) -> Result<Packet, ParseError> {
+    let mut parser = Parser {
+        index,
+        line,
+        position: 0,
+    };
+
+    let packet = parser.packet()?;
+
+    match parser.position == line.len() {
+        true => Ok(packet),
+        false => Err(parser.error("expected the packet to end")),
+    }
+}
+
+pub fn divider_packets() -> [Packet; 2] {
+    [2, 6].map(|n| Packet::List(vec![Packet::List(vec![Packet::Integer(n)])]))
+}
+
+/// Parses pairs of packets on consecutive lines, with blank lines between
+/// the pairs. Every pair has to hold exactly two packets.
+pub fn parse_pairs(input: &str) -> Result<Vec<(Packet, Packet)>, ParseError> {
+    let mut groups = vec![Vec::new()];
+
+    for (index, line) in input.lines().enumerate() {
+        match line.is_empty() {
+            true => groups.push(Vec::new()),
+            false => groups.last_mut().unwrap().push((index, line)),
+        }
+    }
+
+    groups
+        .iter()
+        .filter(|group| !group.is_empty())
+        .map(|group| {
+            let packets = group
+                .iter()
+                .map(|&(index, line)| parse_packet(index, line))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            match <[Packet; 2]>::try_from(packets) {
+                Ok([left, right]) => Ok((left, right)),
+                // Point at the lone packet, or at the first one too many
+                Err(_) => {
+                    let (index, line) = group[group.len().min(3) - 1];
+                    let reason = match group.len() {
+                        1 => "expected a second packet on the next line",
+                        _ => "expected a blank line after a pair of packets",
+                    };
+
+                    Err(ParseError::line(index, line, reason))
+                }
+            }
+        })
+        .collect()
+}
+
+pub mod part1 {
+    use crate::Packet;
+
+    pub fn solve(pairs: &[(Packet, Packet)]) -> usize {
+        pairs
+            .iter()
+            .enumerate()
+            .filter(|(_, (left, right))| left < right)
+            .map(|(index, _)| index + 1)
+            .sum()
+    }
+}
+
+pub mod part2 {
+    use std::ptr;
+
+    use crate::{divider_packets, Packet};
+
+    pub fn solve(pairs: &[(Packet, Packet)]) -> usize {
+        let dividers = divider_packets();
+
+        let mut sorted = pairs
+            .iter()
+            .flat_map(|(left, right)| [left, right])
+            .chain(&dividers)
+            .collect::<Vec<_>>();
+        sorted.sort();
+
+        dividers
+            .iter()
+            .map(|divider| {
+                // Compare addresses, as other packets may well be equal to a divider
+                sorted
+                    .iter()
+                    .position(|&packet| ptr::eq(packet, divider))
+                    .unwrap()
+                    + 1
+            })
+            .product()
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<(Packet, Packet)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_pairs(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day13;
+    use common::Solution;
+    const TEST_INPUT: &str = "[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]
+";
+
+    #[test]
+    fn validate_part1() {
+        assert_eq!(Day13::part1(&Day13::parse(TEST_INPUT).unwrap()), 13);
+    }
+
+    #[test]
+    fn validate_part2() {
+        assert_eq!(Day13::part2(&Day13::parse(TEST_INPUT).unwrap()), 140);
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_offending_token() {
+        let error = Day13::parse("[1,2]\n[1,[2;3]]").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 6));
+        assert_eq!(error.text, ";");
+
+        let error = Day13::parse("[1,[2]").unwrap_err();
+
+        assert_eq!((error.line, error.column), (1, 7));
+        assert_eq!(error.reason, "expected , or ]");
+    }
+
+    #[test]
+    fn pairs_have_exactly_two_packets() {
+        let error = Day13::parse("[1]\n[2]\n\n[3]\n\n[4]\n[5]").unwrap_err();
+
+        assert_eq!(error.line, 4);
+        assert_eq!(error.text, "[3]");
+
+        let error = Day13::parse("[1]\n[2]\n[3]\n\n[4]\n[5]").unwrap_err();
+
+        assert_eq!(error.line, 3);
+        assert_eq!(
+            error.reason,
+            "expected a blank line after a pair of packets"
+        );
+    }
+}
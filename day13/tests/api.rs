@@ -0,0 +1,38 @@
+use common::Solution;
+use day13::{parse_packet, Day13, Packet};
+
+#[test]
+fn parse_pairs_packets_between_blank_lines() {
+    let pairs = Day13::parse("[1]\n[2]\n\n[3]\n[4]\n").unwrap();
+
+    assert_eq!(pairs.len(), 2);
+    assert_eq!(pairs[1].0.to_string(), "[3]");
+    assert_eq!(pairs[1].1.to_string(), "[4]");
+}
+
+#[test]
+fn packets_are_parsed_recursively() {
+    assert_eq!(
+        format!("{:?}", parse_packet(0, "[[],10]").unwrap()),
+        "List([List([]), Integer(10)])"
+    );
+}
+
+#[test]
+fn integers_compare_as_single_element_lists() {
+    let packet = |line| parse_packet(0, line).unwrap();
+
+    assert!(packet("[[1],[2,3,4]]") < packet("[[1],4]"));
+    assert!(packet("[9]") > packet("[[8,7,6]]"));
+    assert!(packet("[[[]]]") > packet("[[]]"));
+    assert_eq!(packet("[[2]]"), packet("[2]"));
+    assert_eq!(Packet::Integer(3), packet("[[3]]"));
+}
+
+#[test]
+fn packets_display_in_canonical_form() {
+    let packet = "[1,[2,[3,[4,[5,6,7]]]],8,9]".parse::<Packet>().unwrap();
+
+    assert_eq!(packet.to_string(), "[1,[2,[3,[4,[5,6,7]]]],8,9]");
+    assert_eq!(serde_json::to_string(&packet).unwrap(), packet.to_string());
+}
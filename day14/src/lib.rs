@@ -0,0 +1,262 @@
+use std::fmt::{self, Display};
+
+use common::{ParseError, Solution};
+use grid::{Grid, Position};
+
+pub type Obstacle = (u32, u32);
+
+/// Where sand enters the cave.
+pub const SOURCE: Obstacle = (500, 0);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Tile::Air => write!(f, "."),
+            Tile::Rock => write!(f, "#"),
+            Tile::Sand => write!(f, "o"),
+        }
+    }
+}
+
+/// The part of the cave above the floor that sand can reach, which is a
+/// triangle spreading out from the source no wider than the cave is deep.
+#[derive(Clone)]
+pub struct Cave {
+    tiles: Grid<Tile>,
+    left: u32,
+    depth: u32,
+}
+
+impl Cave {
+    /// Creates an empty cave with its floor at `depth`.
+    pub fn new(depth: u32) -> Self {
+        let left = SOURCE.0.saturating_sub(depth);
+        let width = SOURCE.0 + depth + 1 - left;
+
+        Self {
+            tiles: Grid::new(width as usize, depth as usize, Tile::Air),
+            left,
+            depth,
+        }
+    }
+
+    pub fn depth(&self) -> u32 {
+        self.depth
+    }
+
+    fn position(&self, x: u32, y: u32) -> Option<Position> {
+        let position = Position::new(x.checked_sub(self.left)? as usize, y as usize);
+
+        self.tiles.contains(position).then_some(position)
+    }
+
+    fn set(&mut self, (x, y): Obstacle, tile: Tile) {
+        if let Some(position) = self.position(x, y) {
+            self.tiles[position] = tile;
+        }
+    }
+
+    /// Adds rock at `obstacle`. Rock that sand can never reach is left out.
+    pub fn add_obstacle(&mut self, obstacle: Obstacle) {
+        self.set(obstacle, Tile::Rock);
+    }
+
+    pub fn add_sand(&mut self, sand: Obstacle) {
+        self.set(sand, Tile::Sand);
+    }
+
+    pub fn check_coordinates(&self, x: u32, y: u32) -> bool {
+        match self.position(x, y) {
+            Some(position) => self.tiles[position] != Tile::Air,
+            None => y >= self.depth,
+        }
+    }
+}
+
+impl Display for Cave {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.tiles)
+    }
+}
+
+fn parse_point(index: usize, line: &str, pos: &str) -> Result<Obstacle, ParseError> {
+    let (x, y) = match pos.split_once(',') {
+        Some(coordinates) => coordinates,
+        None => {
+            return Err(ParseError::new(
+                index,
+                line,
+                pos,
+                "expected a point like 498,4",
+            ))
+        }
+    };
+
+    let parse = |number: &str| {
+        number
+            .parse::<u32>()
+            .map_err(|_| ParseError::new(index, line, number, "expected a coordinate"))
+    };
+
+    Ok((parse(x)?, parse(y)?))
+}
+
+pub fn parse_input(input: &str) -> Result<Cave, ParseError> {
+    let paths = input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            line.split(" -> ")
+                .map(|pos| parse_point(index, line, pos))
+                .collect::<Result<Vec<Obstacle>, ParseError>>()
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    let lowest = paths.iter().flatten().map(|&(_, y)| y).max().unwrap_or(0);
+    let mut cave = Cave::new(lowest + 2);
+
+    for path in paths {
+        path.windows(2).for_each(|window| {
+            let from = window[0];
+            let to = window[1];
+
+            let from_x = from.0.min(to.0);
+            let to_x = from.0.max(to.0);
+
+            let from_y = from.1.min(to.1);
+            let to_y = from.1.max(to.1);
+
+            for x in from_x..=to_x {
+                for y in from_y..=to_y {
+                    cave.add_obstacle((x, y));
+                }
+            }
+        });
+    }
+
+    Ok(cave)
+}
+
+pub mod part1 {
+    use crate::{Cave, Obstacle, SOURCE};
+
+    pub fn solve(cave: &Cave) -> usize {
+        let mut cave = cave.clone();
+        let mut sand_counter = 0;
+        let mut sand: Obstacle;
+
+        'outer: loop {
+            sand = SOURCE;
+
+            loop {
+                if !cave.check_coordinates(sand.0, sand.1 + 1) {
+                    sand = (sand.0, sand.1 + 1);
+                } else if !cave.check_coordinates(sand.0 - 1, sand.1 + 1) {
+                    sand = (sand.0 - 1, sand.1 + 1);
+                } else if !cave.check_coordinates(sand.0 + 1, sand.1 + 1) {
+                    sand = (sand.0 + 1, sand.1 + 1);
+                } else {
+                    cave.add_sand(sand);
+                    sand_counter += 1;
+                    break;
+                }
+
+                if sand.1 + 1 == cave.depth() {
+                    break 'outer;
+                }
+            }
+        }
+
+        sand_counter
+    }
+}
+
+pub mod part2 {
+    use crate::{Cave, Obstacle, SOURCE};
+
+    pub fn solve(cave: &Cave) -> usize {
+        let mut cave = cave.clone();
+        let mut sand_counter = 0;
+        let mut sand: Obstacle;
+
+        while !cave.check_coordinates(SOURCE.0, SOURCE.1) {
+            sand = SOURCE;
+
+            loop {
+                if !cave.check_coordinates(sand.0, sand.1 + 1) {
+                    sand = (sand.0, sand.1 + 1);
+                } else if !cave.check_coordinates(sand.0 - 1, sand.1 + 1) {
+                    sand = (sand.0 - 1, sand.1 + 1);
+                } else if !cave.check_coordinates(sand.0 + 1, sand.1 + 1) {
+                    sand = (sand.0 + 1, sand.1 + 1);
+                } else {
+                    cave.add_sand(sand);
+                    sand_counter += 1;
+                    break;
+                }
+            }
+        }
+
+        sand_counter
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Cave;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day14;
+    use common::Solution;
+    const TEST_INPUT: &str = "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9";
+
+    #[test]
+    fn validate_part1() {
+        assert_eq!(Day14::part1(&Day14::parse(TEST_INPUT).unwrap()), 24);
+    }
+
+    #[test]
+    fn validate_part2() {
+        assert_eq!(Day14::part2(&Day14::parse(TEST_INPUT).unwrap()), 93);
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_offending_token() {
+        let error = Day14::parse("498,4 -> 498,6\n503,4 -> 50x,4")
+            .err()
+            .unwrap();
+
+        assert_eq!((error.line, error.column), (2, 10));
+        assert_eq!(error.text, "50x");
+
+        let error = Day14::parse("498,4 -> 4986").err().unwrap();
+
+        assert_eq!(error.reason, "expected a point like 498,4");
+    }
+}
@@ -0,0 +1,31 @@
+use day14::{parse_input, Cave};
+
+#[test]
+fn paths_become_obstacles() {
+    let cave = parse_input("498,4 -> 498,6 -> 496,6").unwrap();
+
+    assert!(cave.check_coordinates(498, 5));
+    assert!(cave.check_coordinates(497, 6));
+    assert!(!cave.check_coordinates(499, 5));
+    assert_eq!(cave.depth(), 8);
+}
+
+#[test]
+fn floor_blocks_everything_below_it() {
+    let mut cave = Cave::new(2);
+
+    cave.add_obstacle((500, 0));
+
+    assert!(cave.check_coordinates(500, 0));
+    assert!(cave.check_coordinates(123, cave.depth()));
+    assert!(!cave.check_coordinates(123, cave.depth() - 1));
+}
+
+#[test]
+fn cave_renders_rock_and_sand() {
+    let mut cave = parse_input("499,1 -> 501,1").unwrap();
+
+    cave.add_sand((500, 0));
+
+    assert_eq!(cave.to_string(), "...o...\n..###..\n.......");
+}
@@ -0,0 +1,54 @@
+use common::{ParseError, Solution};
+
+pub mod part1 {
+    pub fn solve(_input: &str) -> usize {
+        0
+    }
+}
+
+pub mod part2 {
+    pub fn solve(_input: &str) -> usize {
+        0
+    }
+}
+
+pub struct Dayxx;
+
+impl Solution for Dayxx {
+    const DAY: u8 = 0;
+
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Dayxx;
+    use common::Solution;
+    const TEST_INPUT: &str = "";
+
+    #[test]
+    #[ignore = "the example from the puzzle has not been filled in yet"]
+    fn validate_part1() {
+        assert_eq!(Dayxx::part1(&Dayxx::parse(TEST_INPUT).unwrap()), 1);
+    }
+
+    #[test]
+    #[ignore = "the example from the puzzle has not been filled in yet"]
+    fn validate_part2() {
+        assert_eq!(Dayxx::part2(&Dayxx::parse(TEST_INPUT).unwrap()), 1);
+    }
+}
@@ -0,0 +1,10 @@
+use common::Solution;
+use dayxx::{part1, part2, Dayxx};
+
+#[test]
+fn parts_solve_parsed_input() {
+    let input = Dayxx::parse("").unwrap();
+
+    assert_eq!(part1::solve(&input), Dayxx::part1(&input));
+    assert_eq!(part2::solve(&input), Dayxx::part2(&input));
+}
@@ -0,0 +1,402 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use common::ParseError;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Position {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Position {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// The position one step away in `direction`, unless that would take
+    /// either coordinate below zero.
+    pub fn step(&self, direction: Direction) -> Option<Position> {
+        let (dx, dy) = direction.offset();
+
+        Some(Position {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
+    }
+}
+
+/// A direction on the grid, with y growing downwards.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+}
+
+impl Direction {
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+        Direction::UpLeft,
+        Direction::UpRight,
+        Direction::DownLeft,
+        Direction::DownRight,
+    ];
+
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+            Direction::UpLeft => Direction::DownRight,
+            Direction::UpRight => Direction::DownLeft,
+            Direction::DownLeft => Direction::UpRight,
+            Direction::DownRight => Direction::UpLeft,
+        }
+    }
+
+    pub fn offset(&self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+            Direction::UpLeft => (-1, -1),
+            Direction::UpRight => (1, -1),
+            Direction::DownLeft => (-1, 1),
+            Direction::DownRight => (1, 1),
+        }
+    }
+}
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from cells in row-major order, or returns `None` if
+    /// their number does not fill whole rows of `width`.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Option<Self> {
+        if width == 0 {
+            return cells.is_empty().then_some(Self {
+                width,
+                height: 0,
+                cells,
+            });
+        }
+
+        cells.len().is_multiple_of(width).then(|| Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    /// Parses a map with one character per cell. Every line has to be as
+    /// long as the first, and `cell` decides what each character means,
+    /// returning the reason it is invalid otherwise.
+    pub fn parse<F, E>(input: &str, mut cell: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Result<T, E>,
+        E: Into<String>,
+    {
+        let mut width = None;
+        let mut cells = Vec::new();
+
+        for (index, line) in input.lines().enumerate() {
+            let mut row_width = 0;
+
+            for (offset, c) in line.char_indices() {
+                match cell(c) {
+                    Ok(value) => cells.push(value),
+                    Err(reason) => {
+                        let token = &line[offset..offset + c.len_utf8()];
+                        return Err(ParseError::new(index, line, token, reason));
+                    }
+                }
+
+                row_width += 1;
+            }
+
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(ParseError::line(
+                        index,
+                        line,
+                        format!("row is {row_width} wide, but the first row is {width} wide"),
+                    ))
+                }
+                Some(_) => {}
+            }
+        }
+
+        Ok(Grid::from_cells(width.unwrap_or(0), cells).unwrap())
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.x < self.width && position.y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        match self.contains(position) {
+            true => self.cells.get(position.y * self.width + position.x),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        match self.contains(position) {
+            true => self.cells.get_mut(position.y * self.width + position.x),
+            false => None,
+        }
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Position { x, y }))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn row(&self, y: usize) -> impl DoubleEndedIterator<Item = &T> {
+        let row = match y < self.height {
+            true => &self.cells[y * self.width..(y + 1) * self.width],
+            false => &[],
+        };
+
+        row.iter()
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        let cells = match x < self.width {
+            true => &self.cells[x..],
+            false => &[],
+        };
+
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    /// Positions from `from` in `direction` up to the edge of the grid, not
+    /// including `from` itself.
+    pub fn ray(&self, from: Position, direction: Direction) -> impl Iterator<Item = Position> + '_ {
+        let mut position = from;
+
+        std::iter::from_fn(move || {
+            position = position
+                .step(direction)
+                .filter(|&next| self.contains(next))?;
+            Some(position)
+        })
+    }
+
+    /// The up to 4 positions next to `position`, leaving out the diagonals.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position, &Direction::ORTHOGONAL)
+    }
+
+    /// The up to 8 positions surrounding `position`.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position, &Direction::ALL)
+    }
+
+    fn neighbours(
+        &self,
+        position: Position,
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = Position> + '_ {
+        directions
+            .iter()
+            .filter_map(move |&direction| position.step(direction))
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        match self.get(position) {
+            Some(cell) => cell,
+            None => panic!(
+                "Position ({}, {}) is outside of the {}x{} grid",
+                position.x, position.y, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+
+        match self.get_mut(position) {
+            Some(cell) => cell,
+            None => panic!(
+                "Position ({}, {}) is outside of the {width}x{height} grid",
+                position.x, position.y
+            ),
+        }
+    }
+}
+
+/// Renders the grid one row per line, with every cell written by its own
+/// `Display` implementation.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in self.row(y) {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Direction, Grid, Position};
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(input, |c| c.to_digit(10).ok_or("expected a digit")).unwrap()
+    }
+
+    #[test]
+    fn cells_are_stored_row_by_row() {
+        let grid = digits("123\n456");
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Position::new(2, 0)], 3);
+        assert_eq!(grid.get(Position::new(0, 1)), Some(&4));
+        assert_eq!(grid.get(Position::new(3, 0)), None);
+        assert_eq!(grid.row(1).copied().collect::<Vec<_>>(), [4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+        assert_eq!(grid.row(2).count(), 0);
+    }
+
+    #[test]
+    fn cells_can_be_changed_in_place() {
+        let mut grid = Grid::new(2, 2, '.');
+
+        *grid.get_mut(Position::new(1, 0)).unwrap() = '#';
+        grid[Position::new(0, 1)] = '#';
+
+        assert!(grid.get_mut(Position::new(0, 2)).is_none());
+        assert_eq!(grid.to_string(), ".#\n#.");
+    }
+
+    #[test]
+    fn neighbours_stay_inside_the_grid() {
+        let grid = Grid::new(3, 3, 0);
+        let corner = grid.neighbours4(Position::new(0, 0)).collect::<Vec<_>>();
+
+        assert_eq!(corner, [Position::new(0, 1), Position::new(1, 0)]);
+        assert_eq!(grid.neighbours4(Position::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8(Position::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8(Position::new(2, 0)).count(), 3);
+    }
+
+    #[test]
+    fn rays_run_to_the_edge() {
+        let grid = digits("123\n456\n789");
+        let ray = |direction| {
+            grid.ray(Position::new(1, 1), direction)
+                .map(|position| grid[position])
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(ray(Direction::Up), [2]);
+        assert_eq!(ray(Direction::Up.opposite()), [8]);
+        assert_eq!(ray(Direction::DownRight), [9]);
+        assert_eq!(grid.ray(Position::new(0, 0), Direction::Left).count(), 0);
+        assert_eq!(
+            grid.ray(Position::new(0, 0), Direction::Right)
+                .collect::<Vec<_>>(),
+            [Position::new(1, 0), Position::new(2, 0)]
+        );
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_offending_cell() {
+        let error = Grid::parse("12\n3x", |c| c.to_digit(10).ok_or("expected a digit"));
+        let error = error.unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.reason, "expected a digit");
+
+        let error = Grid::parse("12\n345", Ok::<char, String>).unwrap_err();
+
+        assert_eq!(error.line, 2);
+        assert_eq!(error.reason, "row is 3 wide, but the first row is 2 wide");
+    }
+
+    #[test]
+    fn cells_must_fill_whole_rows() {
+        assert!(Grid::from_cells(3, vec![1, 2, 3, 4]).is_none());
+        assert_eq!(Grid::from_cells(2, vec![1, 2, 3, 4]).unwrap().height(), 2);
+        assert_eq!(
+            Grid::<u8>::parse("", |_| Ok::<u8, String>(0))
+                .unwrap()
+                .width(),
+            0
+        );
+    }
+}